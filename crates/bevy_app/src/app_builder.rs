@@ -1,10 +1,10 @@
 use crate::{
     app::{App, AppExit},
-    event::Events,
+    event::{Events, LocalEvents},
     plugin::Plugin,
     stage, startup_stage,
 };
-use bevy_ecs::{FromResources, IntoQuerySystem, Resources, System, World};
+use bevy_ecs::{FromResources, IntoQuerySystem, IntoThreadLocalSystem, Resources, System, World};
 
 /// Configure [App]s using the builder pattern
 pub struct AppBuilder {
@@ -219,6 +219,19 @@ impl AppBuilder {
             .add_system_to_stage(stage::EVENT_UPDATE, Events::<T>::update_system.system())
     }
 
+    /// Like [AppBuilder::add_event], but for events that are not `Send` or `Sync`. The events are stored in a
+    /// [LocalEvents] thread local resource.
+    pub fn add_local_event<T>(&mut self) -> &mut Self
+    where
+        T: 'static,
+    {
+        self.add_thread_local_resource(LocalEvents::<T>::default())
+            .add_system_to_stage(
+                stage::EVENT_UPDATE,
+                LocalEvents::<T>::update_system.thread_local_system(),
+            )
+    }
+
     pub fn add_resource<T>(&mut self, resource: T) -> &mut Self
     where
         T: Send + Sync + 'static,
@@ -1,5 +1,11 @@
-use bevy_ecs::ResMut;
-use std::marker::PhantomData;
+use bevy_ecs::{ResMut, Resource, Resources, World};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+use thiserror::Error;
 
 #[derive(Debug)]
 struct EventInstance<T> {
@@ -7,6 +13,11 @@ struct EventInstance<T> {
     pub event: T,
 }
 
+/// A unique, monotonically increasing identifier for an event sent to an [Events] collection.
+/// This is the number of events that were sent to the collection before this one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId(pub usize);
+
 #[derive(Debug)]
 enum State {
     A,
@@ -51,29 +62,115 @@ enum State {
 /// [EventReader]s that read at least once per update will never drop events. [EventReader]s that read once within two updates might
 /// still receive some events. [EventReader]s that read after two updates are guaranteed to drop all events that occurred before those updates.
 ///
-/// The buffers in [Events] will grow indefinitely if [Events::update] is never called.
+/// The buffers in [Events] will grow indefinitely if [Events::update] is never called, unless a limit is set using
+/// [Events::set_capacity_limit].
 ///
 /// An alternative call pattern would be to call [Events::update] manually across frames to control when events are cleared. However
 /// this complicates consumption
 #[derive(Debug)]
 pub struct Events<T> {
-    events_a: Vec<EventInstance<T>>,
-    events_b: Vec<EventInstance<T>>,
+    events_a: VecDeque<EventInstance<T>>,
+    events_b: VecDeque<EventInstance<T>>,
+    a_start_event_count: usize,
+    b_start_event_count: usize,
+    event_count: usize,
+    state: State,
+    capacity_limit: Option<usize>,
+    overflow_policy: OverflowPolicy,
+    on_drop: Option<OnDrop<T>>,
+}
+
+/// The buffered events and counters of an [Events] collection, created by [Events::take].
+#[derive(Debug)]
+pub struct EventsSnapshot<T> {
+    events_a: VecDeque<EventInstance<T>>,
+    events_b: VecDeque<EventInstance<T>>,
     a_start_event_count: usize,
     b_start_event_count: usize,
     event_count: usize,
     state: State,
 }
 
+/// A callback that runs for each event [Events::update] discards
+struct OnDrop<T>(Box<dyn Fn(&T) + Send + Sync>);
+
+impl<T> Debug for OnDrop<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OnDrop").finish()
+    }
+}
+
+/// Determines what happens when an event is sent to an [Events] collection that has reached its capacity limit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Removes the oldest buffered event to make room for the new event.
+    DropOldest,
+    /// Discards the new event.
+    DropNewest,
+    /// Panics.
+    Panic,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::DropOldest
+    }
+}
+
 impl<T> Default for Events<T> {
     fn default() -> Self {
         Events {
             a_start_event_count: 0,
             b_start_event_count: 0,
             event_count: 0,
-            events_a: Vec::new(),
-            events_b: Vec::new(),
+            events_a: VecDeque::new(),
+            events_b: VecDeque::new(),
             state: State::A,
+            capacity_limit: None,
+            overflow_policy: OverflowPolicy::default(),
+            on_drop: None,
+        }
+    }
+}
+
+impl<T> Events<T> {
+    /// Returns the oldest and newest event buffers, in that order
+    fn buffers(&self) -> (&VecDeque<EventInstance<T>>, &VecDeque<EventInstance<T>>) {
+        match self.state {
+            State::A => (&self.events_b, &self.events_a),
+            State::B => (&self.events_a, &self.events_b),
+        }
+    }
+
+    /// Returns the [EventId] of the oldest event still stored in the event buffers, if there is one.
+    pub fn oldest_id(&self) -> Option<EventId> {
+        let (oldest, newest) = self.buffers();
+        oldest
+            .front()
+            .or_else(|| newest.front())
+            .map(|instance| EventId(instance.event_count))
+    }
+
+    /// Returns the event count of the earliest event that has not been dropped from the event buffers. Events
+    /// before this were removed by [Events::update] or by the capacity limit.
+    fn earliest_available_event_count(&self) -> usize {
+        let (oldest, oldest_start_event_count, newest_start_event_count) = match self.state {
+            State::A => (
+                &self.events_b,
+                self.b_start_event_count,
+                self.a_start_event_count,
+            ),
+            State::B => (
+                &self.events_a,
+                self.a_start_event_count,
+                self.b_start_event_count,
+            ),
+        };
+
+        if oldest.is_empty() {
+            newest_start_event_count
+        } else {
+            oldest_start_event_count
         }
     }
 }
@@ -83,8 +180,14 @@ fn map_instance_event<T>(event_instance: &EventInstance<T>) -> &T {
 }
 
 /// Reads events of type `T` in order and tracks which events have already been read.
+///
+/// With the `serialize` feature, an EventReader can be serialized to save its position. A deserialized EventReader
+/// only reads the right events if the [Events] it reads from were rebuilt by replaying the same events in the same order.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound = ""))]
 pub struct EventReader<T> {
     last_event_count: usize,
+    #[cfg_attr(feature = "serialize", serde(skip))]
     _marker: PhantomData<T>,
 }
 
@@ -101,49 +204,185 @@ impl<T> EventReader<T> {
     /// Iterates over the events this EventReader has not seen yet. This updates the EventReader's
     /// event counter, which means subsequent event reads will not include events that happened before now.
     pub fn iter<'a>(&mut self, events: &'a Events<T>) -> impl DoubleEndedIterator<Item = &'a T> {
-        // if the reader has seen some of the events in a buffer, find the proper index offset.
-        // otherwise read all events in the buffer
-        let a_index = if self.last_event_count > events.a_start_event_count {
-            self.last_event_count - events.a_start_event_count
-        } else {
-            0
-        };
-        let b_index = if self.last_event_count > events.b_start_event_count {
-            self.last_event_count - events.b_start_event_count
-        } else {
-            0
-        };
-        self.last_event_count = events.event_count;
+        let iter = self.iter_current(events);
+        self.advance(events);
+        iter
+    }
+
+    /// Iterates over at most `max` of the events this EventReader has not seen yet, from oldest to newest. This updates
+    /// the EventReader's event counter past the yielded events only, so the remaining events are read next time.
+    pub fn iter_bounded<'a>(
+        &mut self,
+        events: &'a Events<T>,
+        max: usize,
+    ) -> impl Iterator<Item = &'a T> {
+        let iter = self
+            .iter_unread_instances(events)
+            .take(max)
+            .map(map_instance_event);
+        self.skip(events, max);
+        iter
+    }
+
+    /// Iterates over clones of the events this EventReader has not seen yet. This updates the EventReader's
+    /// event counter, which means subsequent event reads will not include events that happened before now.
+    pub fn iter_cloned<'a>(
+        &mut self,
+        events: &'a Events<T>,
+    ) -> impl DoubleEndedIterator<Item = T> + 'a
+    where
+        T: Clone,
+    {
+        self.iter(events).cloned()
+    }
+
+    /// Iterates over the `n` most recent events in `events` (or fewer, if fewer are buffered), from oldest to newest.
+    /// This includes events this EventReader has already seen and does not update the EventReader's event counter.
+    pub fn iter_last_n<'a>(
+        &self,
+        events: &'a Events<T>,
+        n: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> {
+        let (oldest, newest) = events.buffers();
+        let newest_count = n.min(newest.len());
+        let oldest_count = (n - newest_count).min(oldest.len());
+        oldest
+            .range(oldest.len() - oldest_count..)
+            .chain(newest.range(newest.len() - newest_count..))
+            .map(map_instance_event)
+    }
+
+    /// Iterates over the events this EventReader has not seen yet, without updating the EventReader's event counter.
+    /// Calling this repeatedly will yield the same events until [EventReader::advance] is called.
+    pub fn iter_current<'a>(
+        &self,
+        events: &'a Events<T>,
+    ) -> impl DoubleEndedIterator<Item = &'a T> {
+        self.iter_unread_instances(events).map(map_instance_event)
+    }
+
+    /// Iterates over the events this EventReader has not seen yet, paired with their [EventId]. This updates the
+    /// EventReader's event counter, which means subsequent event reads will not include events that happened before now.
+    pub fn iter_with_id<'a>(
+        &mut self,
+        events: &'a Events<T>,
+    ) -> impl DoubleEndedIterator<Item = (EventId, &'a T)> {
+        let iter = self
+            .iter_unread_instances(events)
+            .map(|instance| (EventId(instance.event_count), &instance.event));
+        self.advance(events);
+        iter
+    }
+
+    fn iter_unread_instances<'a>(
+        &self,
+        events: &'a Events<T>,
+    ) -> impl DoubleEndedIterator<Item = &'a EventInstance<T>> {
+        // fast path: this reader has already seen every event, so there is no need to search the buffers
+        if self.last_event_count >= events.event_count {
+            return events.events_a.range(..0).chain(events.events_b.range(..0));
+        }
+
+        let (a_index, b_index) = self.buffer_indices(events);
         match events.state {
             State::A => events
                 .events_b
-                .get(b_index..)
-                .unwrap_or_else(|| &[])
-                .iter()
-                .map(map_instance_event)
-                .chain(
-                    events
-                        .events_a
-                        .get(a_index..)
-                        .unwrap_or_else(|| &[])
-                        .iter()
-                        .map(map_instance_event),
-                ),
+                .range(b_index..)
+                .chain(events.events_a.range(a_index..)),
             State::B => events
                 .events_a
-                .get(a_index..)
-                .unwrap_or_else(|| &[])
-                .iter()
-                .map(map_instance_event)
-                .chain(
-                    events
-                        .events_b
-                        .get(b_index..)
-                        .unwrap_or_else(|| &[])
-                        .iter()
-                        .map(map_instance_event),
-                ),
+                .range(a_index..)
+                .chain(events.events_b.range(b_index..)),
+        }
+    }
+
+    /// Removes the events this EventReader has not seen yet from `events` and returns them, from oldest to newest. This
+    /// updates the EventReader's event counter. Other [EventReader]s stay valid, but will not read the removed events.
+    pub fn drain_unread(&mut self, events: &mut Events<T>) -> Vec<T> {
+        let (a_index, b_index) = self.buffer_indices(events);
+        let drained_a = events.events_a.split_off(a_index);
+        let drained_b = events.events_b.split_off(b_index);
+        self.advance(events);
+
+        let (oldest, newest) = match events.state {
+            State::A => (drained_b, drained_a),
+            State::B => (drained_a, drained_b),
+        };
+        oldest
+            .into_iter()
+            .chain(newest)
+            .map(|instance| instance.event)
+            .collect()
+    }
+
+    /// Returns the number of events in `events` this EventReader has not seen yet, without updating the EventReader's
+    /// event counter. Events that were dropped by [Events::update] before they were read are not counted.
+    pub fn count_unread(&self, events: &Events<T>) -> usize {
+        let (a_index, b_index) = self.buffer_indices(events);
+        events.events_a.len().saturating_sub(a_index)
+            + events.events_b.len().saturating_sub(b_index)
+    }
+
+    /// Returns the number of events that were dropped from `events` before this EventReader could read them. Systems
+    /// can use this to detect that they are not keeping up with the events being sent.
+    pub fn missed_events(&self, events: &Events<T>) -> usize {
+        events
+            .earliest_available_event_count()
+            .saturating_sub(self.last_event_count)
+    }
+
+    /// Folds the events this EventReader has not seen yet into a single value, starting from `init`. This updates the
+    /// EventReader's event counter, which means subsequent event reads will not include events that happened before now.
+    pub fn fold_unread<A>(&mut self, events: &Events<T>, init: A, f: impl FnMut(A, &T) -> A) -> A {
+        self.iter(events).fold(init, f)
+    }
+
+    /// Retrieves the earliest event in `events` that this reader hasn't seen yet, without updating the EventReader's
+    /// event counter. Subsequent event reads will still include the returned event.
+    pub fn peek<'a>(&self, events: &'a Events<T>) -> Option<&'a T> {
+        self.iter_current(events).next()
+    }
+
+    /// Returns the indices of the first unread event in `events_a` and `events_b` respectively
+    fn buffer_indices(&self, events: &Events<T>) -> (usize, usize) {
+        // buffers are sorted by event_count, but might not be contiguous if events were removed (ex: Events::retain).
+        // find the first event the reader hasn't seen yet. if the reader has seen every event, this is the end of the buffer
+        let first_unread = |buffer: &VecDeque<EventInstance<T>>| {
+            buffer
+                .binary_search_by_key(&self.last_event_count, |instance| instance.event_count)
+                .unwrap_or_else(|index| index)
+        };
+        (
+            first_unread(&events.events_a),
+            first_unread(&events.events_b),
+        )
+    }
+
+    /// Updates the EventReader's event counter to include all events in `events`. Subsequent event reads will not
+    /// include events that happened before now.
+    pub fn advance(&mut self, events: &Events<T>) {
+        self.last_event_count = events.event_count;
+    }
+
+    /// Marks the next `n` events this EventReader has not seen yet as read, without reading them. If there are fewer than
+    /// `n` unread events, this skips all of them.
+    pub fn skip(&mut self, events: &Events<T>, n: usize) {
+        if n == 0 {
+            return;
         }
+
+        self.last_event_count = self
+            .iter_unread_instances(events)
+            .nth(n - 1)
+            .map_or(events.event_count, |instance| instance.event_count + 1);
+    }
+
+    /// Rewinds this EventReader to the oldest event still stored in `events`, so subsequent event reads will include
+    /// every buffered event again, including events this EventReader has already seen.
+    pub fn reset(&mut self, events: &Events<T>) {
+        self.last_event_count = events
+            .oldest_id()
+            .map_or(events.event_count, |oldest_id| oldest_id.0);
     }
 
     /// Retrieves the latest event that this EventReader hasn't seen yet. This updates the EventReader's
@@ -169,22 +408,83 @@ impl<T> EventReader<T> {
     }
 }
 
-impl<T: bevy_ecs::Resource> Events<T> {
+impl<T> Events<T> {
+    /// Creates an empty event collection with room for `capacity` events in each event buffer.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Events {
+            events_a: VecDeque::with_capacity(capacity),
+            events_b: VecDeque::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
     /// "Sends" an `event` by writing it to the current event buffer. [EventReader]s can then read the event.
     pub fn send(&mut self, event: T) {
+        if let Some(limit) = self.capacity_limit {
+            while self.len() >= limit {
+                match self.overflow_policy {
+                    OverflowPolicy::DropOldest => {
+                        // if there is nothing to remove, the new event is the "oldest" event
+                        if !self.remove_oldest() {
+                            return;
+                        }
+                    }
+                    OverflowPolicy::DropNewest => return,
+                    OverflowPolicy::Panic => panic!(
+                        "Events<{}> exceeded its capacity limit of {}",
+                        std::any::type_name::<T>(),
+                        limit
+                    ),
+                }
+            }
+        }
+
         let event_instance = EventInstance {
             event,
             event_count: self.event_count,
         };
 
         match self.state {
-            State::A => self.events_a.push(event_instance),
-            State::B => self.events_b.push(event_instance),
+            State::A => self.events_a.push_back(event_instance),
+            State::B => self.events_b.push_back(event_instance),
         }
 
         self.event_count += 1;
     }
 
+    /// "Sends" an `event` like [Events::send], unless it is equal to the most recent event in the current event buffer.
+    /// This is useful for noisy event sources that repeatedly send the same value.
+    pub fn send_unique(&mut self, event: T)
+    where
+        T: PartialEq,
+    {
+        self.send_if_changed(event);
+    }
+
+    /// Like [Events::send_unique], but returns true if the `event` was sent and false if it was skipped.
+    pub fn send_if_changed(&mut self, event: T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (_, current) = self.buffers();
+        if current.back().map(|instance| &instance.event) == Some(&event) {
+            return false;
+        }
+
+        // the event might still be discarded by the overflow policy
+        let event_count = self.event_count;
+        self.send(event);
+        self.event_count != event_count
+    }
+
+    /// Sends the default value of the event. Useful when the event is an empty struct.
+    pub fn send_default(&mut self)
+    where
+        T: Default,
+    {
+        self.send(Default::default());
+    }
+
     /// Gets a new [EventReader]. This will include all events already in the event buffers.
     pub fn get_reader(&self) -> EventReader<T> {
         EventReader {
@@ -201,35 +501,102 @@ impl<T: bevy_ecs::Resource> Events<T> {
         }
     }
 
+    /// Gets a new [EventReader] that will only read events sent since the last [Events::update] call, along with all
+    /// future events. Unlike [Events::get_reader], it skips events left over from the previous update.
+    pub fn get_reader_since_last_update(&self) -> EventReader<T> {
+        let newest_start_event_count = match self.state {
+            State::A => self.a_start_event_count,
+            State::B => self.b_start_event_count,
+        };
+        EventReader {
+            last_event_count: newest_start_event_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets a new [EventReader] that will read all events sent at or after `event_id`. If some of those events are
+    /// no longer buffered, it will read all events still in the event buffers.
+    pub fn get_reader_from(&self, event_id: usize) -> EventReader<T> {
+        EventReader {
+            last_event_count: event_id,
+            _marker: PhantomData,
+        }
+    }
+
     /// Swaps the event buffers and clears the oldest event buffer. In general, this should be called once per frame/update.
+    /// The cleared buffer keeps its allocated capacity, so it can be reused without reallocating.
     pub fn update(&mut self) {
+        if let Some(OnDrop(on_drop)) = &self.on_drop {
+            let (oldest, _) = self.buffers();
+            for instance in oldest.iter() {
+                on_drop(&instance.event);
+            }
+        }
+
         match self.state {
             State::A => {
-                self.events_b = Vec::new();
+                self.events_b.clear();
                 self.state = State::B;
                 self.b_start_event_count = self.event_count;
             }
             State::B => {
-                self.events_a = Vec::new();
+                self.events_a.clear();
                 self.state = State::A;
                 self.a_start_event_count = self.event_count;
             }
         }
     }
 
-    /// A system that calls [Events::update] once per frame.
-    pub fn update_system(mut events: ResMut<Self>) {
-        events.update();
+    /// Calls [Events::update] only if events were sent since the last update, returning true if it did. Events in the
+    /// oldest buffer are kept until new events are sent, so [EventReader]s that read infrequently can still read them.
+    pub fn update_if_nonempty(&mut self) -> bool {
+        let (_, current) = self.buffers();
+        if current.is_empty() {
+            return false;
+        }
+
+        self.update();
+        true
+    }
+
+    /// Swaps the event buffers like [Events::update], but yields the events that are removed from the oldest buffer
+    /// instead of dropping them. This is useful for processing events that no [EventReader] consumed in time.
+    pub fn update_drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        let buffer = match self.state {
+            State::A => {
+                self.state = State::B;
+                self.b_start_event_count = self.event_count;
+                &mut self.events_b
+            }
+            State::B => {
+                self.state = State::A;
+                self.a_start_event_count = self.event_count;
+                &mut self.events_a
+            }
+        };
+
+        buffer.drain(..).map(|instance| instance.event)
+    }
+
+    /// Sets a callback that [Events::update] runs for each event it discards. This is useful for cleaning up
+    /// resources held by events. Events removed in other ways (ex: [Events::clear] or [Events::update_drain])
+    /// are not passed to the callback.
+    pub fn set_on_drop(&mut self, on_drop: impl Fn(&T) + Send + Sync + 'static) {
+        self.on_drop = Some(OnDrop(Box::new(on_drop)));
     }
 
-    /// Removes all events.
+    /// Removes all events immediately, without waiting for [Events::update] to swap them out.
+    /// `event_count` keeps increasing, so [EventReader]s that are behind will simply read zero events.
     pub fn clear(&mut self) {
+        self.reset_start_event_count();
         self.events_a.clear();
         self.events_b.clear();
     }
 
-    /// Creates a draining iterator that removes all events.
+    /// Creates a draining iterator that removes all events, yielding them from oldest to newest.
     pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.reset_start_event_count();
+
         let map = |i: EventInstance<T>| i.event;
         match self.state {
             State::A => self
@@ -245,15 +612,222 @@ impl<T: bevy_ecs::Resource> Events<T> {
         }
     }
 
+    /// Moves both buffer start counts up to the current `event_count`. Readers that have already
+    /// caught up will then correctly read events sent into the (now empty) buffers.
+    fn reset_start_event_count(&mut self) {
+        self.a_start_event_count = self.event_count;
+        self.b_start_event_count = self.event_count;
+    }
+
+    /// Retains only the events for which `predicate` returns true, visiting them from oldest to newest.
+    /// [EventReader]s will continue to read the remaining events correctly.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&T) -> bool) {
+        let (oldest, newest) = match self.state {
+            State::A => (&mut self.events_b, &mut self.events_a),
+            State::B => (&mut self.events_a, &mut self.events_b),
+        };
+        oldest.retain(|instance| predicate(&instance.event));
+        newest.retain(|instance| predicate(&instance.event));
+    }
+
+    /// Removes the events for which `predicate` returns true and returns them, from oldest to newest. This is the
+    /// opposite of [Events::retain]. [EventReader]s will continue to read the remaining events correctly.
+    pub fn take_matching(&mut self, mut predicate: impl FnMut(&T) -> bool) -> Vec<T> {
+        let (oldest, newest) = match self.state {
+            State::A => (&mut self.events_b, &mut self.events_a),
+            State::B => (&mut self.events_a, &mut self.events_b),
+        };
+
+        let mut taken = Vec::new();
+        let mut take_from = |buffer: &mut VecDeque<EventInstance<T>>| {
+            let (matching, remaining): (VecDeque<_>, VecDeque<_>) = std::mem::take(buffer)
+                .into_iter()
+                .partition(|instance| predicate(&instance.event));
+            *buffer = remaining;
+            taken.extend(matching.into_iter().map(|instance| instance.event));
+        };
+        take_from(oldest);
+        take_from(newest);
+
+        taken
+    }
+
+    /// Removes the events for which `predicate` returns true and sends them to `dest`, from oldest to newest. The moved
+    /// events get new [EventId]s in `dest`.
+    pub fn drain_filtered_into(&mut self, dest: &mut Events<T>, predicate: impl FnMut(&T) -> bool) {
+        dest.extend(self.take_matching(predicate));
+    }
+
+    /// "Sends" all of the given `events` in order. This behaves exactly like calling [Events::send] for each event.
     pub fn extend<I>(&mut self, events: I)
     where
-        I: Iterator<Item = T>,
+        I: IntoIterator<Item = T>,
+    {
+        if self.capacity_limit.is_some() {
+            for event in events {
+                self.send(event);
+            }
+            return;
+        }
+
+        let event_count = &mut self.event_count;
+        let buffer = match self.state {
+            State::A => &mut self.events_a,
+            State::B => &mut self.events_b,
+        };
+
+        buffer.extend(events.into_iter().map(|event| {
+            let event_instance = EventInstance {
+                event,
+                event_count: *event_count,
+            };
+            *event_count += 1;
+            event_instance
+        }));
+    }
+
+    /// "Sends" all of the given `events` in order, returning the [EventId] assigned to each event. These match the ids
+    /// yielded by [EventReader::iter_with_id]. Events discarded by [OverflowPolicy::DropNewest] are not assigned an id.
+    pub fn send_batch_returning_ids<I>(&mut self, events: I) -> Vec<EventId>
+    where
+        I: IntoIterator<Item = T>,
     {
+        let mut ids = Vec::new();
         for event in events {
+            let event_count = self.event_count;
             self.send(event);
+            if self.event_count != event_count {
+                ids.push(EventId(event_count));
+            }
+        }
+
+        ids
+    }
+
+    /// Appends all events buffered in `writer` to the current event buffer, in the order they were sent to the writer.
+    pub fn merge(&mut self, writer: EventWriter<T>) {
+        self.extend(writer.events);
+    }
+
+    /// Moves all events buffered in `other` to the current event buffer, from oldest to newest, leaving `other` empty.
+    /// The moved events are sent after the events already in this collection and get new [EventId]s.
+    pub fn append(&mut self, other: &mut Events<T>) {
+        self.extend(other.drain());
+    }
+
+    /// Removes all events and returns them in a snapshot, along with the counters that track them. `event_count` keeps
+    /// its value, so [EventReader]s will only read events sent after the snapshot was taken.
+    pub fn take(&mut self) -> EventsSnapshot<T> {
+        let snapshot = EventsSnapshot {
+            events_a: std::mem::take(&mut self.events_a),
+            events_b: std::mem::take(&mut self.events_b),
+            a_start_event_count: self.a_start_event_count,
+            b_start_event_count: self.b_start_event_count,
+            event_count: self.event_count,
+            state: std::mem::replace(&mut self.state, State::A),
+        };
+        self.reset_start_event_count();
+        snapshot
+    }
+
+    /// Replaces all events and counters with the contents of `snapshot`. Any events sent since the snapshot was taken
+    /// are dropped. [EventReader]s track their own position, so readers that read events sent after the snapshot was
+    /// taken will read nothing on their next read, and events sent after restoring reuse [EventId]s they have seen.
+    pub fn restore(&mut self, snapshot: EventsSnapshot<T>) {
+        self.events_a = snapshot.events_a;
+        self.events_b = snapshot.events_b;
+        self.a_start_event_count = snapshot.a_start_event_count;
+        self.b_start_event_count = snapshot.b_start_event_count;
+        self.event_count = snapshot.event_count;
+        self.state = snapshot.state;
+    }
+
+    /// Limits the number of events that can be buffered at once to `limit`. When an event is sent while the limit is
+    /// reached, the [OverflowPolicy] set using [Events::set_overflow_policy] applies. `None` removes the limit.
+    pub fn set_capacity_limit(&mut self, limit: Option<usize>) {
+        self.capacity_limit = limit;
+    }
+
+    /// Sets what happens when an event is sent while the capacity limit is reached. Defaults to [OverflowPolicy::DropOldest].
+    pub fn set_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+        self.overflow_policy = overflow_policy;
+    }
+
+    /// Removes the oldest buffered event, returning false if there are no events. The start count of the buffer it was
+    /// removed from moves past the removed event.
+    fn remove_oldest(&mut self) -> bool {
+        let (oldest, oldest_start_event_count, newest, newest_start_event_count) = match self.state
+        {
+            State::A => (
+                &mut self.events_b,
+                &mut self.b_start_event_count,
+                &mut self.events_a,
+                &mut self.a_start_event_count,
+            ),
+            State::B => (
+                &mut self.events_a,
+                &mut self.a_start_event_count,
+                &mut self.events_b,
+                &mut self.b_start_event_count,
+            ),
+        };
+
+        let (buffer, start_event_count) = if oldest.is_empty() {
+            (newest, newest_start_event_count)
+        } else {
+            (oldest, oldest_start_event_count)
+        };
+
+        match buffer.pop_front() {
+            Some(removed) => {
+                *start_event_count = removed.event_count + 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more events in the current event buffer.
+    pub fn reserve(&mut self, additional: usize) {
+        match self.state {
+            State::A => self.events_a.reserve(additional),
+            State::B => self.events_b.reserve(additional),
         }
     }
 
+    /// Returns the combined capacity of both event buffers.
+    pub fn capacity(&self) -> usize {
+        self.events_a.capacity() + self.events_b.capacity()
+    }
+
+    /// Returns the number of events currently stored in both event buffers. Events removed by
+    /// [Events::update] are not counted.
+    pub fn len(&self) -> usize {
+        self.events_a.len() + self.events_b.len()
+    }
+
+    /// Returns true if there are no events currently stored in the event buffers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the most recently sent event that is still stored in the event buffers, if there is one.
+    /// Unlike [EventReader::latest], this doesn't require an [EventReader].
+    pub fn most_recent(&self) -> Option<&T> {
+        let (oldest, newest) = self.buffers();
+        newest
+            .back()
+            .or_else(|| oldest.back())
+            .map(map_instance_event)
+    }
+
+    /// Iterates over every event in both event buffers, from oldest to newest. Unlike [EventReader::iter],
+    /// this doesn't require an [EventReader] and doesn't track which events have been read.
+    pub fn iter_all(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let (oldest, newest) = self.buffers();
+        oldest.iter().chain(newest.iter()).map(map_instance_event)
+    }
+
     /// Iterates over events that happened since the last "update" call.
     /// WARNING: You probably don't want to use this call. In most cases you should use an `EventReader`. You should only use
     /// this if you know you only need to consume events between the last `update()` call and your call to `iter_current_update_events`.
@@ -267,49 +841,192 @@ impl<T: bevy_ecs::Resource> Events<T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<T: Resource> Events<T> {
+    /// A system that calls [Events::update] once per frame.
+    pub fn update_system(mut events: ResMut<Self>) {
+        events.update();
+    }
+}
 
-    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
-    struct TestEvent {
-        i: usize,
+/// An [Events] collection for events that are not `Send` or `Sync`, such as events holding raw OS handles. LocalEvents
+/// are stored as thread local resources, so they can only be accessed from thread local systems. They dereference to
+/// [Events], so they are sent to and read from the same way.
+pub struct LocalEvents<T> {
+    events: Events<T>,
+}
+
+impl<T> Default for LocalEvents<T> {
+    fn default() -> Self {
+        LocalEvents {
+            events: Events::default(),
+        }
     }
+}
 
-    #[test]
-    fn test_events() {
-        let mut events = Events::<TestEvent>::default();
-        let event_0 = TestEvent { i: 0 };
-        let event_1 = TestEvent { i: 1 };
-        let event_2 = TestEvent { i: 2 };
+impl<T> Deref for LocalEvents<T> {
+    type Target = Events<T>;
 
-        // this reader will miss event_0 and event_1 because it wont read them over the course of two updates
-        let mut reader_missed = events.get_reader();
+    fn deref(&self) -> &Events<T> {
+        &self.events
+    }
+}
 
-        let mut reader_a = events.get_reader();
+impl<T> DerefMut for LocalEvents<T> {
+    fn deref_mut(&mut self) -> &mut Events<T> {
+        &mut self.events
+    }
+}
 
-        events.send(event_0);
+impl<T: 'static> LocalEvents<T> {
+    /// A thread local system that calls [Events::update] on the `LocalEvents<T>` thread local resource once per frame.
+    pub fn update_system(_world: &mut World, resources: &mut Resources) {
+        if let Some(mut events) = resources.get_thread_local_mut::<Self>() {
+            events.update();
+        }
+    }
+}
 
-        assert_eq!(
-            get_events(&events, &mut reader_a),
-            vec![event_0],
-            "reader_a created before event receives event"
-        );
-        assert_eq!(
-            get_events(&events, &mut reader_a),
-            vec![],
-            "second iteration of reader_a created before event results in zero events"
-        );
+/// Reads events from [LocalEvents]. This is an [EventReader], which does not require `T` to be `Send` or `Sync`.
+pub type LocalEventReader<T> = EventReader<T>;
 
-        let mut reader_b = events.get_reader();
+/// Buffers events locally so they can be sent without holding exclusive access to [Events].
+/// Buffered events are not visible to [EventReader]s until they are passed to [Events::merge].
+#[derive(Debug)]
+pub struct EventWriter<T> {
+    events: Vec<T>,
+}
 
-        assert_eq!(
-            get_events(&events, &mut reader_b),
-            vec![event_0],
-            "reader_b created after event receives event"
-        );
-        assert_eq!(
-            get_events(&events, &mut reader_b),
+impl<T> Default for EventWriter<T> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+impl<T> EventWriter<T> {
+    /// Buffers an `event` to be sent when this writer is merged into [Events].
+    pub fn send(&mut self, event: T) {
+        self.events.push(event);
+    }
+
+    /// Returns the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there are no buffered events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// An error that occurs when an [EventReader] cannot be created
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EventReaderError {
+    #[error("Events resource does not exist for event type: {type_name}")]
+    MissingEvents { type_name: &'static str },
+}
+
+/// Creates [EventReader]s for [Events] stored in [Resources]
+pub trait GetEventReader {
+    /// Gets a new [EventReader] for the `Events<T>` resource. This will include all events already in the event buffers.
+    /// Panics if the `Events<T>` resource does not exist.
+    fn get_event_reader<T: Resource>(&self) -> EventReader<T>;
+
+    /// Gets a new [EventReader] for the `Events<T>` resource. This will include all events already in the event buffers.
+    /// Returns an error if the `Events<T>` resource does not exist.
+    fn try_get_event_reader<T: Resource>(&self) -> Result<EventReader<T>, EventReaderError>;
+
+    /// Gets a tuple of new [EventReader]s, one for each event type in the tuple.
+    /// Panics if any of the `Events<T>` resources do not exist.
+    fn get_event_readers<R: EventReaders>(&self) -> R;
+}
+
+impl GetEventReader for Resources {
+    fn get_event_reader<T: Resource>(&self) -> EventReader<T> {
+        self.try_get_event_reader::<T>()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_get_event_reader<T: Resource>(&self) -> Result<EventReader<T>, EventReaderError> {
+        self.get::<Events<T>>()
+            .map(|events| events.get_reader())
+            .ok_or(EventReaderError::MissingEvents {
+                type_name: std::any::type_name::<T>(),
+            })
+    }
+
+    fn get_event_readers<R: EventReaders>(&self) -> R {
+        R::try_get(self).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// A tuple of [EventReader]s that can be created together using [GetEventReader::get_event_readers]
+pub trait EventReaders: Sized {
+    fn try_get(resources: &Resources) -> Result<Self, EventReaderError>;
+}
+
+macro_rules! impl_event_readers {
+    ($($name: ident),*) => {
+        impl<$($name: Resource),*> EventReaders for ($(EventReader<$name>,)*) {
+            fn try_get(resources: &Resources) -> Result<Self, EventReaderError> {
+                Ok(($(resources.try_get_event_reader::<$name>()?,)*))
+            }
+        }
+    };
+}
+
+impl_event_readers!(A);
+impl_event_readers!(A, B);
+impl_event_readers!(A, B, C);
+impl_event_readers!(A, B, C, D);
+impl_event_readers!(A, B, C, D, E);
+impl_event_readers!(A, B, C, D, E, F);
+impl_event_readers!(A, B, C, D, E, F, G);
+impl_event_readers!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    struct TestEvent {
+        i: usize,
+    }
+
+    #[test]
+    fn test_events() {
+        let mut events = Events::<TestEvent>::default();
+        let event_0 = TestEvent { i: 0 };
+        let event_1 = TestEvent { i: 1 };
+        let event_2 = TestEvent { i: 2 };
+
+        // this reader will miss event_0 and event_1 because it wont read them over the course of two updates
+        let mut reader_missed = events.get_reader();
+
+        let mut reader_a = events.get_reader();
+
+        events.send(event_0);
+
+        assert_eq!(
+            get_events(&events, &mut reader_a),
+            vec![event_0],
+            "reader_a created before event receives event"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader_a),
+            vec![],
+            "second iteration of reader_a created before event results in zero events"
+        );
+
+        let mut reader_b = events.get_reader();
+
+        assert_eq!(
+            get_events(&events, &mut reader_b),
+            vec![event_0],
+            "reader_b created after event receives event"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader_b),
             vec![],
             "second iteration of reader_b created after event results in zero events"
         );
@@ -366,6 +1083,1325 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_missed_events() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader_missed = events.get_reader();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        assert_eq!(reader_missed.missed_events(&events), 0);
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(get_events(&events, &mut reader).len(), 3);
+        events.update();
+
+        assert_eq!(
+            reader_missed.missed_events(&events),
+            2,
+            "reader_missed missed the two events unread after two update() calls"
+        );
+        assert_eq!(reader.missed_events(&events), 0);
+        assert_eq!(
+            get_events(&events, &mut reader_missed),
+            vec![TestEvent { i: 2 }]
+        );
+        assert_eq!(reader_missed.missed_events(&events), 0);
+
+        let mut events = Events::<TestEvent>::default();
+        let reader = events.get_reader();
+        events.set_capacity_limit(Some(2));
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        assert_eq!(reader.missed_events(&events), 1);
+    }
+
+    #[test]
+    fn test_get_reader_since_last_update() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        assert_eq!(
+            get_events(&events, &mut events.get_reader_since_last_update()),
+            vec![TestEvent { i: 0 }],
+            "before the first update, every event was sent since the last update"
+        );
+
+        events.update();
+        events.send(TestEvent { i: 1 });
+        let mut reader_since_last_update = events.get_reader_since_last_update();
+        let mut reader_current = events.get_reader_current();
+        events.send(TestEvent { i: 2 });
+
+        assert_eq!(
+            get_events(&events, &mut reader_since_last_update),
+            vec![TestEvent { i: 1 }, TestEvent { i: 2 }],
+            "events from before the update are skipped, but events sent since are read"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader_current),
+            vec![TestEvent { i: 2 }],
+            "get_reader_current skips every buffered event"
+        );
+
+        events.update();
+        assert_eq!(
+            get_events(&events, &mut events.get_reader_since_last_update()),
+            vec![],
+            "no events were sent since the last update"
+        );
+    }
+
+    #[test]
+    fn test_iter_bounded() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        for i in 0..10 {
+            events.send(TestEvent { i });
+        }
+
+        let first = reader
+            .iter_bounded(&events, 5)
+            .map(|event| event.i)
+            .collect::<Vec<_>>();
+        assert_eq!(first, vec![0, 1, 2, 3, 4]);
+        assert_eq!(reader.count_unread(&events), 5);
+
+        let rest = reader
+            .iter_bounded(&events, 5)
+            .map(|event| event.i)
+            .collect::<Vec<_>>();
+        assert_eq!(rest, vec![5, 6, 7, 8, 9]);
+        assert_eq!(reader.iter_bounded(&events, 5).count(), 0);
+
+        events.send(TestEvent { i: 10 });
+        assert_eq!(
+            reader
+                .iter_bounded(&events, 5)
+                .map(|event| event.i)
+                .collect::<Vec<_>>(),
+            vec![10],
+            "fewer than max unread events are all yielded"
+        );
+        assert_eq!(reader.count_unread(&events), 0);
+    }
+
+    #[test]
+    fn test_drain_unread() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut other_reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        assert_eq!(
+            get_events(&events, &mut other_reader),
+            vec![TestEvent { i: 0 }]
+        );
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+        events.send(TestEvent { i: 3 });
+
+        assert_eq!(reader.drain_unread(&mut events), vec![TestEvent { i: 3 }]);
+        assert_eq!(
+            events.len(),
+            3,
+            "drained events are removed from the buffers"
+        );
+        assert_eq!(reader.drain_unread(&mut events), vec![]);
+
+        let mut reader = events.get_reader();
+        assert_eq!(
+            reader.drain_unread(&mut events),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+        assert!(events.is_empty());
+        assert_eq!(
+            get_events(&events, &mut other_reader),
+            vec![],
+            "other readers do not read drained events"
+        );
+
+        events.send(TestEvent { i: 4 });
+        assert_eq!(
+            get_events(&events, &mut other_reader),
+            vec![TestEvent { i: 4 }]
+        );
+    }
+
+    #[test]
+    fn test_fold_unread() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        events.update();
+        events.send(TestEvent { i: 3 });
+
+        assert_eq!(
+            reader.fold_unread(&events, 0, |sum, event| sum + event.i),
+            6
+        );
+        assert_eq!(
+            reader.fold_unread(&events, 0, |sum, event| sum + event.i),
+            0,
+            "fold_unread advances the reader"
+        );
+
+        events.send(TestEvent { i: 4 });
+        assert_eq!(
+            reader.fold_unread(&events, 10, |sum, event| sum + event.i),
+            14
+        );
+    }
+
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    struct LocalTestEvent {
+        i: std::rc::Rc<usize>,
+    }
+
+    #[test]
+    fn test_local_events() {
+        let mut resources = Resources::default();
+        resources.insert_thread_local(LocalEvents::<LocalTestEvent>::default());
+        let event_0 = LocalTestEvent { i: 0.into() };
+        let event_1 = LocalTestEvent { i: 1.into() };
+        let event_2 = LocalTestEvent { i: 2.into() };
+
+        let read = |resources: &Resources, reader: &mut LocalEventReader<LocalTestEvent>| {
+            let events = resources
+                .get_thread_local_mut::<LocalEvents<LocalTestEvent>>()
+                .unwrap();
+            reader.iter(&events).cloned().collect::<Vec<_>>()
+        };
+        let send = |resources: &Resources, event: &LocalTestEvent| {
+            resources
+                .get_thread_local_mut::<LocalEvents<LocalTestEvent>>()
+                .unwrap()
+                .send(event.clone());
+        };
+        let new_reader = |resources: &Resources| {
+            resources
+                .get_thread_local_mut::<LocalEvents<LocalTestEvent>>()
+                .unwrap()
+                .get_reader()
+        };
+
+        // this reader will miss event_0 and event_1 because it wont read them over the course of two updates
+        let mut reader_missed = new_reader(&resources);
+        let mut reader_a = new_reader(&resources);
+
+        send(&resources, &event_0);
+        assert_eq!(
+            read(&resources, &mut reader_a),
+            vec![event_0.clone()],
+            "reader_a created before event receives event"
+        );
+        assert_eq!(
+            read(&resources, &mut reader_a),
+            vec![],
+            "second iteration of reader_a created before event results in zero events"
+        );
+
+        let mut reader_b = new_reader(&resources);
+        assert_eq!(
+            read(&resources, &mut reader_b),
+            vec![event_0.clone()],
+            "reader_b created after event receives event"
+        );
+
+        send(&resources, &event_1);
+        assert_eq!(
+            read(&resources, &mut reader_a),
+            vec![event_1.clone()],
+            "reader_a receives next unread event"
+        );
+
+        LocalEvents::<LocalTestEvent>::update_system(&mut World::default(), &mut resources);
+        let mut reader_d = new_reader(&resources);
+        send(&resources, &event_2);
+
+        assert_eq!(
+            read(&resources, &mut reader_a),
+            vec![event_2.clone()],
+            "reader_a receives event created after update"
+        );
+        assert_eq!(
+            read(&resources, &mut reader_b),
+            vec![event_1.clone(), event_2.clone()],
+            "reader_b receives events created before and after update"
+        );
+        assert_eq!(
+            read(&resources, &mut reader_d),
+            vec![event_0, event_1, event_2.clone()],
+            "reader_d receives all events created before and after update"
+        );
+
+        LocalEvents::<LocalTestEvent>::update_system(&mut World::default(), &mut resources);
+        assert_eq!(
+            read(&resources, &mut reader_missed),
+            vec![event_2],
+            "reader_missed missed events unread after two update() calls"
+        );
+    }
+
+    #[test]
+    fn test_events_drain() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+
+        assert_eq!(
+            events.drain().collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }, TestEvent { i: 2 }],
+            "drain yields events from the oldest buffer first"
+        );
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            vec![],
+            "a fresh reader sees nothing after drain"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![],
+            "an existing reader sees nothing after drain"
+        );
+
+        events.send(TestEvent { i: 3 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 3 }],
+            "an existing reader receives events sent after drain"
+        );
+
+        events.update();
+        events.send(TestEvent { i: 4 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 4 }],
+            "an existing reader receives events sent after drain and update"
+        );
+    }
+
+    #[test]
+    fn test_events_clear() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader_lagging = events.get_reader();
+        let mut reader_current = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        assert_eq!(
+            get_events(&events, &mut reader_current),
+            vec![TestEvent { i: 0 }]
+        );
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+
+        events.clear();
+
+        assert_eq!(
+            get_events(&events, &mut reader_lagging),
+            vec![],
+            "lagging reader reads zero events after clear"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader_current),
+            vec![],
+            "partially caught up reader reads zero events after clear"
+        );
+
+        events.send(TestEvent { i: 3 });
+        assert_eq!(
+            get_events(&events, &mut reader_lagging),
+            vec![TestEvent { i: 3 }],
+            "reader receives events sent after clear"
+        );
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            vec![TestEvent { i: 3 }],
+            "fresh reader only receives events sent after clear"
+        );
+    }
+
+    #[test]
+    fn test_events_len() {
+        let mut events = Events::<TestEvent>::default();
+        assert!(events.is_empty());
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        assert_eq!(events.len(), 3);
+        assert!(!events.is_empty());
+
+        events.update();
+        assert_eq!(events.len(), 3, "events remain after one update");
+
+        events.send(TestEvent { i: 3 });
+        assert_eq!(events.len(), 4);
+
+        events.update();
+        assert_eq!(
+            events.len(),
+            1,
+            "events from before the last two updates are dropped"
+        );
+
+        events.update();
+        assert_eq!(events.len(), 0);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_event_reader_iter_current() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+
+        let first = reader.iter_current(&events).cloned().collect::<Vec<_>>();
+        let second = reader.iter_current(&events).cloned().collect::<Vec<_>>();
+        assert_eq!(first, vec![TestEvent { i: 0 }, TestEvent { i: 1 }]);
+        assert_eq!(
+            first, second,
+            "iter_current yields the same events until advance is called"
+        );
+
+        reader.advance(&events);
+        assert_eq!(reader.iter_current(&events).count(), 0);
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 2 }],
+            "iter only yields events sent after advance"
+        );
+    }
+
+    #[test]
+    fn test_event_reader_peek() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.peek(&events), None);
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+
+        assert_eq!(reader.peek(&events), Some(&TestEvent { i: 0 }));
+        assert_eq!(
+            reader.peek(&events),
+            Some(&TestEvent { i: 0 }),
+            "peek does not advance the reader"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }],
+            "iter still yields peeked events"
+        );
+        assert_eq!(reader.peek(&events), None);
+    }
+
+    #[test]
+    fn test_event_reader_count_unread() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut reader_missed = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        assert_eq!(events.get_reader_current().count_unread(&events), 0);
+        assert_eq!(reader.count_unread(&events), 2);
+        assert_eq!(
+            reader.count_unread(&events),
+            2,
+            "count_unread does not advance"
+        );
+
+        get_events(&events, &mut reader);
+        assert_eq!(reader.count_unread(&events), 0);
+
+        events.update();
+        events.send(TestEvent { i: 2 });
+        assert_eq!(reader.count_unread(&events), 1);
+        assert_eq!(reader_missed.count_unread(&events), 3);
+
+        events.update();
+        events.send(TestEvent { i: 3 });
+        assert_eq!(reader.count_unread(&events), 2);
+        assert_eq!(
+            reader_missed.count_unread(&events),
+            2,
+            "lagging reader only counts events that are still buffered"
+        );
+        assert_eq!(
+            reader_missed.count_unread(&events),
+            get_events(&events, &mut reader_missed).len()
+        );
+    }
+
+    #[test]
+    fn test_events_retain() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut reader_partial = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        assert_eq!(
+            get_events(&events, &mut reader_partial),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+        events.update();
+        events.send(TestEvent { i: 2 });
+        events.send(TestEvent { i: 3 });
+        events.send(TestEvent { i: 4 });
+
+        events.retain(|event| event.i % 2 == 0);
+
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 2 }, TestEvent { i: 4 }],
+            "reader only receives retained events"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader_partial),
+            vec![TestEvent { i: 2 }, TestEvent { i: 4 }],
+            "reader past the removed events receives the remaining retained events"
+        );
+
+        events.send(TestEvent { i: 5 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 5 }],
+            "reader receives events sent after retain"
+        );
+    }
+
+    #[test]
+    fn test_events_take_matching() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+        events.send(TestEvent { i: 3 });
+
+        assert_eq!(
+            events.take_matching(|event| event.i % 2 == 1),
+            vec![TestEvent { i: 1 }, TestEvent { i: 3 }],
+            "matching events are returned from oldest to newest"
+        );
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 2 }],
+            "reader only receives the remaining events"
+        );
+
+        events.send(TestEvent { i: 4 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 4 }],
+            "reader receives events sent after take_matching"
+        );
+        assert!(events.take_matching(|event| event.i > 4).is_empty());
+    }
+
+    #[test]
+    fn test_events_drain_filtered_into() {
+        let mut events = Events::<TestEvent>::default();
+        let mut urgent = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut urgent_reader = urgent.get_reader();
+
+        urgent.send(TestEvent { i: 100 });
+        for i in 0..3 {
+            events.send(TestEvent { i });
+        }
+        events.update();
+        for i in 3..5 {
+            events.send(TestEvent { i });
+        }
+
+        events.drain_filtered_into(&mut urgent, |event| event.i % 2 == 0);
+
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }, TestEvent { i: 3 }],
+            "matching events are removed from the source"
+        );
+        assert_eq!(
+            get_events(&urgent, &mut urgent_reader),
+            vec![
+                TestEvent { i: 100 },
+                TestEvent { i: 0 },
+                TestEvent { i: 2 },
+                TestEvent { i: 4 }
+            ],
+            "matching events are sent to the destination in order"
+        );
+        assert_eq!(
+            urgent
+                .get_reader()
+                .iter_with_id(&urgent)
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            vec![EventId(0), EventId(1), EventId(2), EventId(3)],
+            "moved events get new ids in the destination"
+        );
+    }
+
+    #[test]
+    fn test_events_append() {
+        let mut events = Events::<TestEvent>::default();
+        let mut other = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut other_reader = other.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        other.send(TestEvent { i: 1 });
+        other.update();
+        other.send(TestEvent { i: 2 });
+
+        events.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }, TestEvent { i: 2 }],
+            "appended events are read after existing events, in order"
+        );
+        assert_eq!(events.most_recent(), Some(&TestEvent { i: 2 }));
+
+        assert_eq!(get_events(&other, &mut other_reader), vec![]);
+        other.send(TestEvent { i: 3 });
+        assert_eq!(
+            get_events(&other, &mut other_reader),
+            vec![TestEvent { i: 3 }],
+            "the emptied collection can still be used"
+        );
+    }
+
+    #[test]
+    fn test_events_extend() {
+        let mut events_sent = Events::<TestEvent>::default();
+        let mut events_extended = Events::<TestEvent>::default();
+        let mut reader_sent = events_sent.get_reader();
+        let mut reader_extended = events_extended.get_reader();
+
+        for i in 0..3 {
+            events_sent.send(TestEvent { i });
+        }
+        events_extended.extend((0..3).map(|i| TestEvent { i }));
+        events_sent.update();
+        events_extended.update();
+        for i in 3..5 {
+            events_sent.send(TestEvent { i });
+        }
+        events_extended.extend(vec![TestEvent { i: 3 }, TestEvent { i: 4 }]);
+
+        assert_eq!(events_sent.event_count, events_extended.event_count);
+        assert_eq!(
+            get_events(&events_extended, &mut reader_extended),
+            get_events(&events_sent, &mut reader_sent),
+            "extend behaves like repeated calls to send"
+        );
+
+        events_extended.extend(vec![TestEvent { i: 5 }]);
+        assert_eq!(
+            get_events(&events_extended, &mut reader_extended),
+            vec![TestEvent { i: 5 }]
+        );
+    }
+
+    #[test]
+    fn test_event_reader_iter_with_id() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        assert_eq!(events.oldest_id(), None);
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        let before_update = reader.iter_with_id(&events).collect::<Vec<_>>();
+        assert_eq!(
+            before_update,
+            vec![
+                (EventId(0), &TestEvent { i: 0 }),
+                (EventId(1), &TestEvent { i: 1 })
+            ]
+        );
+
+        events.update();
+        events.send(TestEvent { i: 2 });
+        assert_eq!(events.oldest_id(), Some(EventId(0)));
+        let after_update = events
+            .get_reader()
+            .iter_with_id(&events)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            after_update,
+            vec![EventId(0), EventId(1), EventId(2)],
+            "ids are stable across updates"
+        );
+        assert!(after_update.windows(2).all(|ids| ids[0] < ids[1]));
+        assert_eq!(
+            reader.iter_with_id(&events).collect::<Vec<_>>(),
+            vec![(EventId(2), &TestEvent { i: 2 })]
+        );
+
+        events.update();
+        assert_eq!(events.oldest_id(), Some(EventId(2)));
+    }
+
+    #[test]
+    fn test_try_get_event_reader() {
+        let mut resources = Resources::default();
+        assert_eq!(
+            resources.try_get_event_reader::<TestEvent>().err(),
+            Some(EventReaderError::MissingEvents {
+                type_name: std::any::type_name::<TestEvent>()
+            })
+        );
+
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        resources.insert(events);
+        let mut reader = resources.try_get_event_reader::<TestEvent>().unwrap();
+        assert_eq!(
+            get_events(&resources.get::<Events<TestEvent>>().unwrap(), &mut reader),
+            vec![TestEvent { i: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_get_reader_from() {
+        let mut events = Events::<TestEvent>::default();
+        for i in 0..3 {
+            events.send(TestEvent { i });
+        }
+        events.update();
+        for i in 3..5 {
+            events.send(TestEvent { i });
+        }
+
+        assert_eq!(
+            get_events(&events, &mut events.get_reader_from(2)),
+            vec![TestEvent { i: 2 }, TestEvent { i: 3 }, TestEvent { i: 4 }],
+            "reader reads events starting at the given id"
+        );
+        assert_eq!(get_events(&events, &mut events.get_reader_from(5)), vec![]);
+
+        events.update();
+        assert_eq!(
+            get_events(&events, &mut events.get_reader_from(1)),
+            vec![TestEvent { i: 3 }, TestEvent { i: 4 }],
+            "reader starting before the buffered events reads everything still buffered"
+        );
+    }
+
+    #[test]
+    fn test_events_reserve() {
+        let mut events = Events::<TestEvent>::default();
+        events.reserve(100);
+        assert!(events.capacity() >= 100);
+
+        for i in 0..100 {
+            events.send(TestEvent { i });
+        }
+        events.update();
+        events.update();
+        assert!(events.is_empty());
+        assert!(events.capacity() >= 100, "capacity is kept across updates");
+    }
+
+    #[test]
+    fn test_events_with_capacity() {
+        let mut events = Events::<TestEvent>::with_capacity(50);
+        assert!(events.capacity() >= 100, "both buffers are preallocated");
+        assert!(events.is_empty());
+
+        let capacity = events.capacity();
+        for i in 0..50 {
+            events.send(TestEvent { i });
+        }
+        events.update();
+        for i in 50..100 {
+            events.send(TestEvent { i });
+        }
+        assert_eq!(events.capacity(), capacity, "no buffer was reallocated");
+    }
+
+    #[test]
+    fn test_events_update_reuses_buffers() {
+        let mut events = Events::<TestEvent>::default();
+        for i in 0..10 {
+            events.send(TestEvent { i });
+        }
+        let ptr = events.events_a.as_slices().0.as_ptr();
+        let capacity = events.events_a.capacity();
+
+        events.update();
+        events.update();
+        assert!(events.events_a.is_empty());
+        for i in 10..20 {
+            events.send(TestEvent { i });
+        }
+
+        assert_eq!(
+            events.events_a.as_slices().0.as_ptr(),
+            ptr,
+            "buffer allocation is reused after two updates"
+        );
+        assert_eq!(events.events_a.capacity(), capacity);
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            (10..20).map(|i| TestEvent { i }).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_events_send_default() {
+        #[derive(Default, PartialEq, Debug)]
+        struct EmptyTestEvent;
+
+        let mut events = Events::<EmptyTestEvent>::default();
+        let mut reader = events.get_reader();
+        events.send_default();
+        events.send_default();
+
+        assert_eq!(
+            reader.iter(&events).collect::<Vec<_>>(),
+            vec![&EmptyTestEvent, &EmptyTestEvent]
+        );
+        assert_eq!(events.event_count, 2);
+    }
+
+    #[test]
+    fn test_event_reader_iter_cloned() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+
+        assert_eq!(
+            reader.iter_cloned(&events).collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+        assert_eq!(
+            reader.iter_cloned(&events).collect::<Vec<TestEvent>>(),
+            vec![],
+            "iter_cloned advances the reader"
+        );
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(
+            reader.iter_cloned(&events).collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 2 }]
+        );
+    }
+
+    #[test]
+    fn test_event_reader_iter_last_n() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.iter_last_n(&events, 3).count(), 0);
+
+        for i in 0..8 {
+            events.send(TestEvent { i });
+        }
+        events.update();
+        for i in 8..10 {
+            events.send(TestEvent { i });
+        }
+
+        assert_eq!(
+            reader.iter_last_n(&events, 3).cloned().collect::<Vec<_>>(),
+            vec![TestEvent { i: 7 }, TestEvent { i: 8 }, TestEvent { i: 9 }]
+        );
+        assert_eq!(
+            reader.iter_last_n(&events, 1).cloned().collect::<Vec<_>>(),
+            vec![TestEvent { i: 9 }]
+        );
+        assert_eq!(
+            reader.iter_last_n(&events, 20).count(),
+            10,
+            "requesting more events than are buffered returns all of them"
+        );
+        assert_eq!(
+            reader.count_unread(&events),
+            10,
+            "iter_last_n does not advance the reader"
+        );
+
+        get_events(&events, &mut reader);
+        assert_eq!(
+            reader.iter_last_n(&events, 2).cloned().collect::<Vec<_>>(),
+            vec![TestEvent { i: 8 }, TestEvent { i: 9 }],
+            "iter_last_n includes events the reader has already seen"
+        );
+    }
+
+    #[test]
+    fn test_events_send_unique() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+
+        events.send_unique(TestEvent { i: 0 });
+        events.send_unique(TestEvent { i: 0 });
+        events.send_unique(TestEvent { i: 0 });
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 0 }]);
+
+        events.send_unique(TestEvent { i: 1 });
+        events.send_unique(TestEvent { i: 0 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }, TestEvent { i: 0 }],
+            "only consecutive duplicates are skipped"
+        );
+
+        events.update();
+        events.send_unique(TestEvent { i: 0 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }],
+            "events are sent when the current buffer is empty"
+        );
+    }
+
+    #[test]
+    fn test_events_merge() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        events.send(TestEvent { i: 0 });
+
+        let mut writer_a = EventWriter::default();
+        writer_a.send(TestEvent { i: 1 });
+        writer_a.send(TestEvent { i: 2 });
+        let mut writer_b = EventWriter::default();
+        writer_b.send(TestEvent { i: 3 });
+        assert_eq!(events.len(), 1, "buffered events are not sent until merged");
+
+        events.merge(writer_b);
+        events.merge(writer_a);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![
+                TestEvent { i: 0 },
+                TestEvent { i: 3 },
+                TestEvent { i: 1 },
+                TestEvent { i: 2 }
+            ],
+            "events are appended in merge order"
+        );
+        assert_eq!(events.event_count, 4);
+        assert_eq!(events.oldest_id(), Some(EventId(0)));
+
+        let mut reader = events.get_reader_from(2);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }, TestEvent { i: 2 }],
+            "merged events are assigned sequential event counts"
+        );
+    }
+
+    #[test]
+    fn test_events_update_drain() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+
+        assert_eq!(
+            events.update_drain().collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }],
+            "update_drain yields the events update would drop"
+        );
+        assert_eq!(events.len(), 1);
+
+        let mut reader = events.get_reader();
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 2 }]);
+
+        assert_eq!(
+            events.update_drain().collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 2 }]
+        );
+        assert!(events.is_empty());
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    struct OtherTestEvent {
+        value: bool,
+    }
+
+    #[test]
+    fn test_get_event_readers() {
+        let mut resources = Resources::default();
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        resources.insert(events);
+        assert_eq!(
+            <(EventReader<TestEvent>, EventReader<OtherTestEvent>)>::try_get(&resources).err(),
+            Some(EventReaderError::MissingEvents {
+                type_name: std::any::type_name::<OtherTestEvent>()
+            })
+        );
+
+        let mut other_events = Events::<OtherTestEvent>::default();
+        other_events.send(OtherTestEvent { value: true });
+        resources.insert(other_events);
+
+        let (mut reader, mut other_reader): (EventReader<TestEvent>, EventReader<OtherTestEvent>) =
+            resources.get_event_readers();
+        assert_eq!(
+            get_events(&resources.get::<Events<TestEvent>>().unwrap(), &mut reader),
+            vec![TestEvent { i: 0 }]
+        );
+        assert_eq!(
+            other_reader
+                .iter(&resources.get::<Events<OtherTestEvent>>().unwrap())
+                .cloned()
+                .collect::<Vec<OtherTestEvent>>(),
+            vec![OtherTestEvent { value: true }]
+        );
+    }
+
+    #[test]
+    fn test_event_reader_caught_up() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        let mut reader = events.get_reader_current();
+
+        assert_eq!(reader.iter(&events).count(), 0);
+        assert_eq!(reader.last_event_count, events.event_count);
+
+        events.update();
+        assert_eq!(reader.iter(&events).count(), 0);
+        assert_eq!(reader.last_event_count, events.event_count);
+    }
+
+    #[test]
+    fn test_events_iter_all() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+
+        let all = events.iter_all().cloned().collect::<Vec<TestEvent>>();
+        assert_eq!(
+            all,
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+        assert_eq!(all, get_events(&events, &mut events.get_reader()));
+        assert_eq!(
+            events.iter_all().count(),
+            3,
+            "iter_all doesn't consume events"
+        );
+    }
+
+    #[test]
+    fn test_events_overflow_drop_oldest() {
+        let mut events = Events::<TestEvent>::default();
+        events.set_capacity_limit(Some(2));
+        let mut reader = events.get_reader();
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        assert_eq!(events.len(), 2);
+        assert_eq!(events.event_count, 3);
+        assert_eq!(events.oldest_id(), Some(EventId(1)));
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 1 }, TestEvent { i: 2 }],
+            "readers skip dropped events"
+        );
+
+        events.extend(vec![TestEvent { i: 3 }, TestEvent { i: 4 }]);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 3 }, TestEvent { i: 4 }],
+            "extend respects the capacity limit"
+        );
+
+        events.set_capacity_limit(Some(0));
+        events.send(TestEvent { i: 5 });
+        assert!(events.is_empty());
+        assert_eq!(events.event_count, 5);
+    }
+
+    #[test]
+    fn test_events_overflow_drop_newest() {
+        let mut events = Events::<TestEvent>::default();
+        events.set_capacity_limit(Some(2));
+        events.set_overflow_policy(OverflowPolicy::DropNewest);
+        let mut reader = events.get_reader();
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.send(TestEvent { i: 2 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+
+        events.set_capacity_limit(None);
+        events.send(TestEvent { i: 3 });
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 3 }]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_events_overflow_panic() {
+        let mut events = Events::<TestEvent>::default();
+        events.set_capacity_limit(Some(1));
+        events.set_overflow_policy(OverflowPolicy::Panic);
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+    }
+
+    #[test]
+    fn test_events_send_batch_returning_ids() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        let mut reader = events.get_reader_current();
+
+        let ids = events.send_batch_returning_ids(vec![TestEvent { i: 1 }, TestEvent { i: 2 }]);
+        assert_eq!(ids, vec![EventId(1), EventId(2)]);
+        assert_eq!(
+            reader
+                .iter_with_id(&events)
+                .map(|(id, _)| id)
+                .collect::<Vec<EventId>>(),
+            ids
+        );
+    }
+
+    #[test]
+    fn test_event_reader_reset() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+
+        let mut reader = events.get_reader();
+        let all = get_events(&events, &mut reader);
+        assert_eq!(all, vec![TestEvent { i: 1 }, TestEvent { i: 2 }]);
+        assert!(get_events(&events, &mut reader).is_empty());
+
+        reader.reset(&events);
+        assert_eq!(
+            reader.last_event_count, 1,
+            "reset stops at the oldest buffered event"
+        );
+        assert_eq!(get_events(&events, &mut reader), all);
+
+        events.clear();
+        reader.reset(&events);
+        assert_eq!(reader.last_event_count, events.event_count);
+    }
+
+    #[test]
+    fn test_events_on_drop() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let mut events = Events::<TestEvent>::default();
+        let counter = dropped.clone();
+        events.set_on_drop(move |_event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        events.update();
+        assert_eq!(
+            dropped.load(Ordering::SeqCst),
+            0,
+            "events are kept for one update"
+        );
+
+        events.send(TestEvent { i: 2 });
+        events.update();
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+        events.update();
+        assert_eq!(dropped.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_events_send_if_changed() {
+        let mut events = Events::<TestEvent>::default();
+        assert!(events.send_if_changed(TestEvent { i: 0 }));
+        assert!(!events.send_if_changed(TestEvent { i: 0 }));
+        assert!(!events.send_if_changed(TestEvent { i: 0 }));
+        assert!(events.send_if_changed(TestEvent { i: 1 }));
+        assert_eq!(events.len(), 2);
+
+        events.update();
+        assert!(
+            events.send_if_changed(TestEvent { i: 1 }),
+            "events are sent when the current buffer is empty"
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_event_reader_serialize() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        let mut reader = events.get_reader();
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 0 }]);
+
+        let serialized = ron::to_string(&reader).unwrap();
+        let mut replayed_events = Events::<TestEvent>::default();
+        replayed_events.send(TestEvent { i: 0 });
+        replayed_events.send(TestEvent { i: 1 });
+        let mut deserialized: EventReader<TestEvent> = ron::from_str(&serialized).unwrap();
+        assert_eq!(
+            get_events(&replayed_events, &mut deserialized),
+            vec![TestEvent { i: 1 }],
+            "the deserialized reader resumes where the original left off"
+        );
+    }
+
+    #[test]
+    fn test_events_most_recent() {
+        let mut events = Events::<TestEvent>::default();
+        assert_eq!(events.most_recent(), None);
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        assert_eq!(events.most_recent(), Some(&TestEvent { i: 1 }));
+
+        events.update();
+        assert_eq!(
+            events.most_recent(),
+            Some(&TestEvent { i: 1 }),
+            "the most recent event can be in the oldest buffer"
+        );
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(events.most_recent(), Some(&TestEvent { i: 2 }));
+
+        events.update();
+        events.update();
+        assert_eq!(events.most_recent(), None);
+    }
+
+    #[test]
+    fn test_events_take_restore() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+
+        let snapshot = events.take();
+        assert!(events.is_empty());
+        assert_eq!(events.event_count, 2);
+
+        events.send(TestEvent { i: 2 });
+        let mut reader = events.get_reader();
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 2 }]);
+
+        events.restore(snapshot);
+        assert_eq!(events.event_count, 2);
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+        assert!(
+            get_events(&events, &mut reader).is_empty(),
+            "readers that are ahead of a restored snapshot read nothing"
+        );
+
+        events.send(TestEvent { i: 3 });
+        let ids = reader
+            .iter_with_id(&events)
+            .map(|(id, _)| id)
+            .collect::<Vec<EventId>>();
+        assert_eq!(
+            ids,
+            vec![EventId(2)],
+            "event ids are reused after restoring"
+        );
+
+        events.update();
+        assert_eq!(
+            events.iter_all().cloned().collect::<Vec<TestEvent>>(),
+            vec![TestEvent { i: 1 }, TestEvent { i: 3 }],
+            "the buffer state is restored"
+        );
+    }
+
+    #[test]
+    fn test_events_update_if_nonempty() {
+        let mut events = Events::<TestEvent>::default();
+        assert!(!events.update_if_nonempty());
+
+        events.send(TestEvent { i: 0 });
+        assert!(events.update_if_nonempty());
+        assert!(
+            !events.update_if_nonempty(),
+            "no swap happens when no events were sent"
+        );
+        assert!(!events.update_if_nonempty());
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            vec![TestEvent { i: 0 }]
+        );
+
+        events.send(TestEvent { i: 1 });
+        assert!(events.update_if_nonempty());
+        assert_eq!(
+            get_events(&events, &mut events.get_reader()),
+            vec![TestEvent { i: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_event_reader_skip() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        events.extend((0..5).map(|i| TestEvent { i }));
+
+        reader.skip(&events, 0);
+        assert_eq!(reader.count_unread(&events), 5);
+
+        reader.skip(&events, 2);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 2 }, TestEvent { i: 3 }, TestEvent { i: 4 }]
+        );
+
+        events.extend((5..7).map(|i| TestEvent { i }));
+        reader.skip(&events, 10);
+        assert_eq!(reader.last_event_count, events.event_count);
+        assert!(get_events(&events, &mut reader).is_empty());
+    }
+
     fn get_events(
         events: &Events<TestEvent>,
         reader: &mut EventReader<TestEvent>,
@@ -15,7 +15,7 @@ pub mod prelude {
             GamepadEventType,
         },
         keyboard::KeyCode,
-        mouse::MouseButton,
+        mouse::{MouseButton, MouseMotionReader},
         Axis, Input,
     };
 }
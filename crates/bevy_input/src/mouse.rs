@@ -27,6 +27,20 @@ pub struct MouseMotion {
     pub delta: Vec2,
 }
 
+/// Helpers for reading [MouseMotion] events
+pub trait MouseMotionReader {
+    /// Sums the deltas of the [MouseMotion] events this reader has not seen yet. This updates the reader's event
+    /// counter. If there are no new events, the delta is zero.
+    fn accumulate_delta(&mut self, events: &Events<MouseMotion>) -> Vec2;
+}
+
+impl MouseMotionReader for EventReader<MouseMotion> {
+    fn accumulate_delta(&mut self, events: &Events<MouseMotion>) -> Vec2 {
+        self.iter(events)
+            .fold(Vec2::zero(), |delta, motion| delta + motion.delta)
+    }
+}
+
 /// Unit of scroll
 #[derive(Debug, Clone, Copy)]
 pub enum MouseScrollUnit {
@@ -65,3 +79,31 @@ pub fn mouse_button_input_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulate_delta() {
+        let mut events = Events::<MouseMotion>::default();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.accumulate_delta(&events), Vec2::zero());
+
+        events.send(MouseMotion {
+            delta: Vec2::new(1.0, 2.0),
+        });
+        events.send(MouseMotion {
+            delta: Vec2::new(-3.0, 0.5),
+        });
+        events.send(MouseMotion {
+            delta: Vec2::new(0.5, 0.5),
+        });
+        assert_eq!(reader.accumulate_delta(&events), Vec2::new(-1.5, 3.0));
+        assert_eq!(
+            reader.accumulate_delta(&events),
+            Vec2::zero(),
+            "events are only accumulated once"
+        );
+    }
+}
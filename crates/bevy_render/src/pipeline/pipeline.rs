@@ -1,16 +1,19 @@
 use super::{
     state_descriptors::{
         BlendDescriptor, BlendFactor, BlendOperation, ColorStateDescriptor, ColorWrite,
-        CompareFunction, CullMode, DepthStencilStateDescriptor, FrontFace, IndexFormat,
-        PrimitiveTopology, RasterizationStateDescriptor, StencilStateFaceDescriptor,
+        CompareFunction, DepthStencilStateDescriptor, IndexFormat, PrimitiveTopology,
+        RasterizationStateDescriptor,
     },
-    BindType, DynamicBinding, PipelineLayout, StencilStateDescriptor, VertexBufferDescriptors,
+    BindType, DynamicBinding, PipelineLayout, VertexBufferDescriptors,
 };
 use crate::{
     shader::{Shader, ShaderStages},
     texture::TextureFormat,
 };
 use bevy_asset::Assets;
+use bevy_utils::AHasher;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
 
 #[derive(Clone, Debug)]
 pub struct PipelineDescriptor {
@@ -45,6 +48,24 @@ pub struct PipelineDescriptor {
     pub alpha_to_coverage_enabled: bool,
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PipelineValidationError {
+    #[error("Pipeline sample count must be a power of two.")]
+    SampleCountNotPowerOfTwo { sample_count: u32 },
+    #[error("Pipeline sample count must be 1, 2, 4 or 8.")]
+    UnsupportedSampleCount { sample_count: u32 },
+    #[error("Pipeline alpha to coverage requires a sample count greater than one.")]
+    AlphaToCoverageWithoutMultisampling,
+    #[error("Pipeline depth stencil state does not use a depth format.")]
+    NonDepthFormat { format: TextureFormat },
+    #[error("Pipeline has neither color states nor a depth stencil state.")]
+    NoOutputStates,
+    #[error("Pipeline color state uses a depth format.")]
+    DepthFormatColorState { format: TextureFormat },
+    #[error("Pipeline has multiple color states with the same format.")]
+    DuplicateColorStateFormat { format: TextureFormat },
+}
+
 impl PipelineDescriptor {
     pub fn new(shader_stages: ShaderStages) -> Self {
         PipelineDescriptor {
@@ -63,6 +84,19 @@ impl PipelineDescriptor {
     }
 
     pub fn default_config(shader_stages: ShaderStages) -> Self {
+        Self::default_config_for(
+            shader_stages,
+            TextureFormat::Bgra8UnormSrgb,
+            TextureFormat::Depth32Float,
+        )
+    }
+
+    /// Like [PipelineDescriptor::default_config], but targets the given color and depth formats.
+    pub fn default_config_for(
+        shader_stages: ShaderStages,
+        color_format: TextureFormat,
+        depth_format: TextureFormat,
+    ) -> Self {
         PipelineDescriptor {
             name: None,
             primitive_topology: PrimitiveTopology::TriangleList,
@@ -71,27 +105,13 @@ impl PipelineDescriptor {
             sample_count: 1,
             sample_mask: !0,
             alpha_to_coverage_enabled: false,
-            rasterization_state: Some(RasterizationStateDescriptor {
-                front_face: FrontFace::Ccw,
-                cull_mode: CullMode::Back,
-                depth_bias: 0,
-                depth_bias_slope_scale: 0.0,
-                depth_bias_clamp: 0.0,
-                clamp_depth: false,
-            }),
-            depth_stencil_state: Some(DepthStencilStateDescriptor {
-                format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: CompareFunction::Less,
-                stencil: StencilStateDescriptor {
-                    front: StencilStateFaceDescriptor::IGNORE,
-                    back: StencilStateFaceDescriptor::IGNORE,
-                    read_mask: 0,
-                    write_mask: 0,
-                },
-            }),
+            rasterization_state: Some(RasterizationStateDescriptor::default_culled()),
+            depth_stencil_state: Some(DepthStencilStateDescriptor::depth_only(
+                depth_format,
+                CompareFunction::Less,
+            )),
             color_states: vec![ColorStateDescriptor {
-                format: TextureFormat::Bgra8UnormSrgb,
+                format: color_format,
                 color_blend: BlendDescriptor {
                     src_factor: BlendFactor::SrcAlpha,
                     dst_factor: BlendFactor::OneMinusSrcAlpha,
@@ -108,6 +128,140 @@ impl PipelineDescriptor {
         }
     }
 
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Returns true if this descriptor is named `name`. Unnamed descriptors never match.
+    pub fn name_matches(&self, name: &str) -> bool {
+        self.name.as_deref() == Some(name)
+    }
+
+    /// Clones this descriptor, replacing its fragment shader with `shader`, which is added to `shaders`.
+    pub fn clone_with_fragment_shader(
+        &self,
+        shaders: &mut Assets<Shader>,
+        shader: Shader,
+    ) -> PipelineDescriptor {
+        let mut descriptor = self.clone();
+        descriptor.shader_stages.fragment = Some(shaders.add(shader));
+        descriptor
+    }
+
+    /// Checks for configuration errors that would otherwise only be reported by the GPU when the pipeline is created.
+    pub fn validate(&self) -> Result<(), PipelineValidationError> {
+        if !self.sample_count.is_power_of_two() {
+            return Err(PipelineValidationError::SampleCountNotPowerOfTwo {
+                sample_count: self.sample_count,
+            });
+        }
+
+        if self.sample_count > 8 {
+            return Err(PipelineValidationError::UnsupportedSampleCount {
+                sample_count: self.sample_count,
+            });
+        }
+
+        if self.alpha_to_coverage_enabled && self.sample_count == 1 {
+            return Err(PipelineValidationError::AlphaToCoverageWithoutMultisampling);
+        }
+
+        if let Some(ref depth_stencil_state) = self.depth_stencil_state {
+            let format = depth_stencil_state.format;
+            if !format.is_depth_format() {
+                return Err(PipelineValidationError::NonDepthFormat { format });
+            }
+        }
+
+        if self.color_states.is_empty() && self.depth_stencil_state.is_none() {
+            return Err(PipelineValidationError::NoOutputStates);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the formats of this descriptor's color states, in attachment order.
+    pub fn color_state_formats(&self) -> Vec<TextureFormat> {
+        self.color_states
+            .iter()
+            .map(|color_state| color_state.format)
+            .collect()
+    }
+
+    /// Checks that every color state targets a color format and that no two color states share a format. This is
+    /// stricter than [PipelineDescriptor::validate] and is meant for render targets that expect one attachment per format.
+    pub fn validate_color_states(&self) -> Result<(), PipelineValidationError> {
+        let formats = self.color_state_formats();
+        for (i, format) in formats.iter().enumerate() {
+            if format.is_depth_format() {
+                return Err(PipelineValidationError::DepthFormatColorState { format: *format });
+            }
+
+            if formats[..i].contains(format) {
+                return Err(PipelineValidationError::DuplicateColorStateFormat { format: *format });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if this descriptor can be used in a render pass with the given color attachment formats, depth
+    /// attachment format and sample count.
+    pub fn is_compatible_with(
+        &self,
+        color_formats: &[TextureFormat],
+        depth_format: Option<TextureFormat>,
+        sample_count: u32,
+    ) -> bool {
+        self.sample_count == sample_count
+            && self
+                .color_states
+                .iter()
+                .map(|color_state| color_state.format)
+                .eq(color_formats.iter().copied())
+            && self
+                .depth_stencil_state
+                .as_ref()
+                .map(|depth_stencil_state| depth_stencil_state.format)
+                == depth_format
+    }
+
+    /// Hashes every field that affects the compiled pipeline, so descriptors with the same key can share a compiled
+    /// pipeline. The descriptor's `name` is not included.
+    ///
+    /// The key is only stable within one process: the hasher's output may change between bevy versions, and shader
+    /// handles are usually random, so keys should not be persisted or compared across runs.
+    pub fn hash_key(&self) -> u64 {
+        let mut hasher = AHasher::default();
+        self.layout.hash(&mut hasher);
+        self.shader_stages.hash(&mut hasher);
+        // RasterizationStateDescriptor has f32 fields, so it can't derive Hash
+        self.rasterization_state.is_some().hash(&mut hasher);
+        if let Some(ref rasterization_state) = self.rasterization_state {
+            rasterization_state.front_face.hash(&mut hasher);
+            rasterization_state.cull_mode.hash(&mut hasher);
+            rasterization_state.depth_bias.hash(&mut hasher);
+            rasterization_state
+                .depth_bias_slope_scale
+                .to_bits()
+                .hash(&mut hasher);
+            rasterization_state
+                .depth_bias_clamp
+                .to_bits()
+                .hash(&mut hasher);
+            rasterization_state.clamp_depth.hash(&mut hasher);
+        }
+        self.primitive_topology.hash(&mut hasher);
+        self.color_states.hash(&mut hasher);
+        self.depth_stencil_state.hash(&mut hasher);
+        self.index_format.hash(&mut hasher);
+        self.sample_count.hash(&mut hasher);
+        self.sample_mask.hash(&mut hasher);
+        self.alpha_to_coverage_enabled.hash(&mut hasher);
+        hasher.finish()
+    }
+
     pub fn get_layout(&self) -> Option<&PipelineLayout> {
         self.layout.as_ref()
     }
@@ -179,3 +333,231 @@ impl PipelineDescriptor {
         self.layout = Some(layout);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shader::ShaderStage;
+    use bevy_asset::Handle;
+
+    fn test_descriptor() -> PipelineDescriptor {
+        PipelineDescriptor::default_config(ShaderStages::new(Handle::new()))
+    }
+
+    #[test]
+    fn test_default_config_for() {
+        let descriptor = PipelineDescriptor::default_config_for(
+            ShaderStages::new(Handle::new()),
+            TextureFormat::Rgba16Float,
+            TextureFormat::Depth24Plus,
+        );
+        assert_eq!(descriptor.color_states.len(), 1);
+        assert_eq!(
+            descriptor.color_states[0].format,
+            TextureFormat::Rgba16Float
+        );
+        assert_eq!(
+            descriptor.depth_stencil_state.unwrap().format,
+            TextureFormat::Depth24Plus
+        );
+
+        let descriptor = test_descriptor();
+        assert_eq!(
+            descriptor.color_states[0].format,
+            TextureFormat::Bgra8UnormSrgb
+        );
+        assert_eq!(
+            descriptor.depth_stencil_state.unwrap().format,
+            TextureFormat::Depth32Float
+        );
+    }
+
+    #[test]
+    fn test_with_name() {
+        let descriptor = test_descriptor();
+        assert!(descriptor.name.is_none());
+        assert!(!descriptor.name_matches(""));
+
+        let descriptor = descriptor.with_name("forward");
+        assert_eq!(descriptor.name.as_deref(), Some("forward"));
+        assert!(descriptor.name_matches("forward"));
+        assert!(!descriptor.name_matches("ui"));
+
+        let descriptor = descriptor.with_name("ui");
+        assert!(descriptor.name_matches("ui"));
+    }
+
+    #[test]
+    fn test_clone_with_fragment_shader() {
+        let mut shaders = Assets::<Shader>::default();
+        let descriptor = PipelineDescriptor {
+            name: Some("base".to_string()),
+            sample_count: 4,
+            ..PipelineDescriptor::default_config(ShaderStages {
+                vertex: shaders.add(Shader::from_glsl(ShaderStage::Vertex, "")),
+                fragment: Some(shaders.add(Shader::from_glsl(ShaderStage::Fragment, ""))),
+            })
+        };
+
+        let variant = descriptor
+            .clone_with_fragment_shader(&mut shaders, Shader::from_glsl(ShaderStage::Fragment, ""));
+        let variant_fragment = variant.shader_stages.fragment.unwrap();
+        assert_ne!(Some(variant_fragment), descriptor.shader_stages.fragment);
+        assert!(shaders.get(&variant_fragment).is_some());
+
+        assert_eq!(
+            variant.shader_stages.vertex,
+            descriptor.shader_stages.vertex
+        );
+        assert_eq!(variant.name, descriptor.name);
+        assert_eq!(variant.sample_count, descriptor.sample_count);
+        assert_eq!(variant.primitive_topology, descriptor.primitive_topology);
+        assert_eq!(variant.color_states.len(), descriptor.color_states.len());
+        assert!(variant.depth_stencil_state.is_some());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(test_descriptor().validate(), Ok(()));
+
+        let mut descriptor = test_descriptor();
+        descriptor.sample_count = 0;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::SampleCountNotPowerOfTwo { sample_count: 0 })
+        );
+
+        let mut descriptor = test_descriptor();
+        descriptor.sample_count = 3;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::SampleCountNotPowerOfTwo { sample_count: 3 })
+        );
+
+        let mut descriptor = test_descriptor();
+        descriptor.sample_count = 16;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::UnsupportedSampleCount { sample_count: 16 })
+        );
+        descriptor.sample_count = 8;
+        assert_eq!(descriptor.validate(), Ok(()));
+
+        let mut descriptor = test_descriptor();
+        descriptor.alpha_to_coverage_enabled = true;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::AlphaToCoverageWithoutMultisampling)
+        );
+        descriptor.sample_count = 4;
+        assert_eq!(descriptor.validate(), Ok(()));
+
+        let mut descriptor = test_descriptor();
+        descriptor.depth_stencil_state.as_mut().unwrap().format = TextureFormat::Bgra8UnormSrgb;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::NonDepthFormat {
+                format: TextureFormat::Bgra8UnormSrgb
+            })
+        );
+
+        let mut descriptor = test_descriptor();
+        descriptor.color_states.clear();
+        assert_eq!(
+            descriptor.validate(),
+            Ok(()),
+            "depth only pipelines are valid"
+        );
+        descriptor.depth_stencil_state = None;
+        assert_eq!(
+            descriptor.validate(),
+            Err(PipelineValidationError::NoOutputStates)
+        );
+    }
+
+    #[test]
+    fn test_validate_color_states() {
+        let mut descriptor = test_descriptor();
+        let mut color_state = descriptor.color_states[0].clone();
+        color_state.format = TextureFormat::Rgba16Float;
+        descriptor.color_states.push(color_state);
+        assert_eq!(
+            descriptor.color_state_formats(),
+            vec![TextureFormat::Bgra8UnormSrgb, TextureFormat::Rgba16Float]
+        );
+        assert_eq!(descriptor.validate_color_states(), Ok(()));
+
+        descriptor.color_states[1].format = TextureFormat::Bgra8UnormSrgb;
+        assert_eq!(
+            descriptor.validate_color_states(),
+            Err(PipelineValidationError::DuplicateColorStateFormat {
+                format: TextureFormat::Bgra8UnormSrgb
+            })
+        );
+
+        descriptor.color_states[1].format = TextureFormat::Depth32Float;
+        assert_eq!(
+            descriptor.validate_color_states(),
+            Err(PipelineValidationError::DepthFormatColorState {
+                format: TextureFormat::Depth32Float
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_compatible_with() {
+        let descriptor = test_descriptor();
+        let color_formats = [TextureFormat::Bgra8UnormSrgb];
+        let depth_format = Some(TextureFormat::Depth32Float);
+        assert!(descriptor.is_compatible_with(&color_formats, depth_format, 1));
+
+        assert!(!descriptor.is_compatible_with(&[TextureFormat::Rgba8Unorm], depth_format, 1));
+        assert!(!descriptor.is_compatible_with(
+            &[TextureFormat::Bgra8UnormSrgb, TextureFormat::Bgra8UnormSrgb],
+            depth_format,
+            1
+        ));
+        assert!(!descriptor.is_compatible_with(
+            &color_formats,
+            Some(TextureFormat::Depth24Plus),
+            1
+        ));
+        assert!(!descriptor.is_compatible_with(&color_formats, depth_format, 4));
+        assert!(!descriptor.is_compatible_with(&color_formats, None, 1));
+
+        let mut descriptor = test_descriptor();
+        descriptor.depth_stencil_state = None;
+        assert!(descriptor.is_compatible_with(&color_formats, None, 1));
+        assert!(!descriptor.is_compatible_with(&color_formats, depth_format, 1));
+    }
+
+    #[test]
+    fn test_hash_key() {
+        let descriptor = test_descriptor();
+        assert_eq!(descriptor.hash_key(), descriptor.clone().hash_key());
+        assert_eq!(
+            descriptor.hash_key(),
+            PipelineDescriptor::default_config(descriptor.shader_stages.clone()).hash_key(),
+            "separately built equal descriptors have the same hash key"
+        );
+        assert_eq!(
+            descriptor.hash_key(),
+            descriptor.clone().with_name("other").hash_key(),
+            "names do not affect the hash key"
+        );
+
+        let mut line_list = descriptor.clone();
+        line_list.primitive_topology = PrimitiveTopology::LineList;
+        assert_ne!(descriptor.hash_key(), line_list.hash_key());
+
+        let mut no_cull = descriptor.clone();
+        no_cull.rasterization_state = Some(RasterizationStateDescriptor::default_no_cull());
+        assert_ne!(descriptor.hash_key(), no_cull.hash_key());
+
+        assert_ne!(
+            descriptor.hash_key(),
+            test_descriptor().hash_key(),
+            "shader handles affect the hash key"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use crate::shader::{ShaderLayout, GL_VERTEX_INDEX};
 use bevy_utils::HashMap;
 use std::hash::Hash;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Hash)]
 pub struct PipelineLayout {
     pub bind_groups: Vec<BindGroupDescriptor>,
     pub vertex_buffer_descriptors: Vec<VertexBufferDescriptor>,
@@ -16,6 +16,25 @@ impl PipelineLayout {
             .find(|bind_group| bind_group.index == index)
     }
 
+    pub fn get_bind_group_mut(&mut self, index: u32) -> Option<&mut BindGroupDescriptor> {
+        self.bind_groups
+            .iter_mut()
+            .find(|bind_group| bind_group.index == index)
+    }
+
+    /// Adds `other`'s bind groups to this layout. If both layouts have a bind group with the same index, the bind group
+    /// from `other` replaces the one in this layout. Bind groups stay sorted by index.
+    pub fn merge(&mut self, other: &PipelineLayout) {
+        for bind_group in other.bind_groups.iter() {
+            match self.get_bind_group_mut(bind_group.index) {
+                Some(existing) => *existing = bind_group.clone(),
+                None => self.bind_groups.push(bind_group.clone()),
+            }
+        }
+
+        self.bind_groups.sort_by_key(|bind_group| bind_group.index);
+    }
+
     pub fn from_shader_layouts(shader_layouts: &mut [ShaderLayout]) -> Self {
         let mut bind_groups = HashMap::<u32, BindGroupDescriptor>::default();
         let mut vertex_buffer_descriptors = Vec::new();
@@ -124,3 +143,69 @@ impl UniformProperty {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{BindType, BindingDescriptor, BindingShaderStage};
+
+    #[test]
+    fn test_get_bind_group() {
+        let mut layout = PipelineLayout {
+            bind_groups: vec![
+                BindGroupDescriptor::new(2, Vec::new()),
+                BindGroupDescriptor::new(0, Vec::new()),
+            ],
+            vertex_buffer_descriptors: Vec::new(),
+        };
+
+        assert_eq!(layout.get_bind_group(0).unwrap().index, 0);
+        assert_eq!(layout.get_bind_group(2).unwrap().index, 2);
+        assert!(layout.get_bind_group(1).is_none());
+
+        layout.get_bind_group_mut(2).unwrap().index = 1;
+        assert!(layout.get_bind_group(2).is_none());
+        assert_eq!(layout.get_bind_group(1).unwrap().index, 1);
+        assert!(layout.get_bind_group_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_merge() {
+        let sampler = BindingDescriptor {
+            name: "Sampler".to_string(),
+            index: 0,
+            bind_type: BindType::Sampler { comparison: false },
+            shader_stage: BindingShaderStage::FRAGMENT,
+        };
+        let mut layout = PipelineLayout {
+            bind_groups: vec![
+                BindGroupDescriptor::new(0, Vec::new()),
+                BindGroupDescriptor::new(2, Vec::new()),
+            ],
+            vertex_buffer_descriptors: Vec::new(),
+        };
+        let other = PipelineLayout {
+            bind_groups: vec![
+                BindGroupDescriptor::new(2, vec![sampler.clone()]),
+                BindGroupDescriptor::new(1, Vec::new()),
+            ],
+            vertex_buffer_descriptors: Vec::new(),
+        };
+
+        layout.merge(&other);
+        assert_eq!(
+            layout
+                .bind_groups
+                .iter()
+                .map(|bind_group| bind_group.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            layout.get_bind_group(2).unwrap().bindings,
+            vec![sampler],
+            "the bind group from the merged layout overrides the existing one"
+        );
+        assert!(layout.get_bind_group(0).unwrap().bindings.is_empty());
+    }
+}
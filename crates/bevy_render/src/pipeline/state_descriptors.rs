@@ -1,8 +1,10 @@
 use crate::texture::TextureFormat;
 use bevy_property::Property;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 pub struct DepthStencilStateDescriptor {
     pub format: TextureFormat,
     pub depth_write_enabled: bool,
@@ -10,7 +12,33 @@ pub struct DepthStencilStateDescriptor {
     pub stencil: StencilStateDescriptor,
 }
 
-#[derive(Clone, Debug)]
+impl DepthStencilStateDescriptor {
+    /// A depth state that writes depth values using `compare` and ignores the stencil aspect.
+    pub fn depth_only(format: TextureFormat, compare: CompareFunction) -> Self {
+        DepthStencilStateDescriptor {
+            format,
+            depth_write_enabled: true,
+            depth_compare: compare,
+            stencil: StencilStateDescriptor {
+                front: StencilStateFaceDescriptor::IGNORE,
+                back: StencilStateFaceDescriptor::IGNORE,
+                read_mask: 0,
+                write_mask: 0,
+            },
+        }
+    }
+
+    /// Like [DepthStencilStateDescriptor::depth_only], but only tests depth values without writing them. This is
+    /// useful for transparent passes.
+    pub fn read_only(format: TextureFormat, compare: CompareFunction) -> Self {
+        DepthStencilStateDescriptor {
+            depth_write_enabled: false,
+            ..Self::depth_only(format, compare)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 pub struct StencilStateDescriptor {
     pub front: StencilStateFaceDescriptor,
     pub back: StencilStateFaceDescriptor,
@@ -18,7 +46,7 @@ pub struct StencilStateDescriptor {
     pub write_mask: u32,
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StencilOperation {
     Keep = 0,
     Zero = 1,
@@ -30,7 +58,7 @@ pub enum StencilOperation {
     DecrementWrap = 7,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 pub struct StencilStateFaceDescriptor {
     pub compare: CompareFunction,
     pub fail_op: StencilOperation,
@@ -45,9 +73,27 @@ impl StencilStateFaceDescriptor {
         depth_fail_op: StencilOperation::Keep,
         pass_op: StencilOperation::Keep,
     };
+
+    /// Always passes the stencil test and writes the reference value when the depth test passes. Useful for
+    /// marking the pixels covered by a mask.
+    pub const ALWAYS_REPLACE: Self = StencilStateFaceDescriptor {
+        compare: CompareFunction::Always,
+        fail_op: StencilOperation::Keep,
+        depth_fail_op: StencilOperation::Keep,
+        pass_op: StencilOperation::Replace,
+    };
+
+    /// Passes the stencil test only where the stencil value equals the reference value, without modifying it.
+    /// Useful for drawing only inside a mask written with [StencilStateFaceDescriptor::ALWAYS_REPLACE].
+    pub const KEEP_IF_EQUAL: Self = StencilStateFaceDescriptor {
+        compare: CompareFunction::Equal,
+        fail_op: StencilOperation::Keep,
+        depth_fail_op: StencilOperation::Keep,
+        pass_op: StencilOperation::Keep,
+    };
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum CompareFunction {
     Never = 0,
     Less = 1,
@@ -59,6 +105,24 @@ pub enum CompareFunction {
     Always = 7,
 }
 
+impl CompareFunction {
+    /// Returns the comparison that results from swapping the compared values (ex: `Less` becomes `Greater`).
+    /// This converts depth comparisons between standard and reversed-Z depth buffers. `Never`, `Equal`, `NotEqual`
+    /// and `Always` are symmetric, so they are returned unchanged.
+    pub fn inverse(self) -> CompareFunction {
+        match self {
+            CompareFunction::Less => CompareFunction::Greater,
+            CompareFunction::LessEqual => CompareFunction::GreaterEqual,
+            CompareFunction::Greater => CompareFunction::Less,
+            CompareFunction::GreaterEqual => CompareFunction::LessEqual,
+            CompareFunction::Never
+            | CompareFunction::Equal
+            | CompareFunction::NotEqual
+            | CompareFunction::Always => self,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize, Property)]
 pub enum PrimitiveTopology {
     PointList = 0,
@@ -74,7 +138,43 @@ impl Default for PrimitiveTopology {
     }
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+impl PrimitiveTopology {
+    /// Returns true for the strip topologies ([PrimitiveTopology::LineStrip] and [PrimitiveTopology::TriangleStrip]).
+    ///
+    /// Indexed strips use primitive restart: an index of `0xFFFF` (for [IndexFormat::Uint16]) or `0xFFFFFFFF`
+    /// (for [IndexFormat::Uint32]) ends the current strip and starts a new one. This means the maximum index
+    /// value of the pipeline's `index_format` cannot be used to reference a vertex when drawing strips.
+    pub fn is_strip(&self) -> bool {
+        match self {
+            PrimitiveTopology::LineStrip | PrimitiveTopology::TriangleStrip => true,
+            PrimitiveTopology::PointList
+            | PrimitiveTopology::LineList
+            | PrimitiveTopology::TriangleList => false,
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Unknown primitive topology \"{0}\". Expected one of: point-list, line-list, line-strip, triangle-list, triangle-strip.")]
+pub struct UnknownPrimitiveTopology(pub String);
+
+impl FromStr for PrimitiveTopology {
+    type Err = UnknownPrimitiveTopology;
+
+    /// Parses the WebGPU name of a primitive topology, such as "triangle-list"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "point-list" => Ok(PrimitiveTopology::PointList),
+            "line-list" => Ok(PrimitiveTopology::LineList),
+            "line-strip" => Ok(PrimitiveTopology::LineStrip),
+            "triangle-list" => Ok(PrimitiveTopology::TriangleList),
+            "triangle-strip" => Ok(PrimitiveTopology::TriangleStrip),
+            _ => Err(UnknownPrimitiveTopology(s.to_string())),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum FrontFace {
     Ccw = 0,
     Cw = 1,
@@ -86,7 +186,17 @@ impl Default for FrontFace {
     }
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+impl FrontFace {
+    /// Returns the opposite winding order. Mirrored geometry (ex: reflections) flips the winding order of its faces.
+    pub fn flip(self) -> FrontFace {
+        match self {
+            FrontFace::Ccw => FrontFace::Cw,
+            FrontFace::Cw => FrontFace::Ccw,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum CullMode {
     None = 0,
     Front = 1,
@@ -99,7 +209,18 @@ impl Default for CullMode {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+impl CullMode {
+    /// Culls the opposite faces. [CullMode::None] is returned unchanged.
+    pub fn flip(self) -> CullMode {
+        match self {
+            CullMode::None => CullMode::None,
+            CullMode::Front => CullMode::Back,
+            CullMode::Back => CullMode::Front,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct RasterizationStateDescriptor {
     pub front_face: FrontFace,
     pub cull_mode: CullMode,
@@ -109,7 +230,29 @@ pub struct RasterizationStateDescriptor {
     pub clamp_depth: bool,
 }
 
-#[derive(Clone, Debug)]
+impl RasterizationStateDescriptor {
+    /// Counter-clockwise front faces with back faces culled, and no depth bias.
+    pub fn default_culled() -> Self {
+        RasterizationStateDescriptor {
+            cull_mode: CullMode::Back,
+            ..Self::default_no_cull()
+        }
+    }
+
+    /// Counter-clockwise front faces without culling, and no depth bias. Useful for double-sided materials.
+    pub fn default_no_cull() -> Self {
+        RasterizationStateDescriptor {
+            front_face: FrontFace::Ccw,
+            cull_mode: CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+            clamp_depth: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 pub struct ColorStateDescriptor {
     pub format: TextureFormat,
     pub alpha_blend: BlendDescriptor,
@@ -117,7 +260,86 @@ pub struct ColorStateDescriptor {
     pub write_mask: ColorWrite,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl ColorStateDescriptor {
+    /// Overwrites the target with the source color and alpha.
+    pub fn opaque(format: TextureFormat) -> Self {
+        ColorStateDescriptor {
+            format,
+            color_blend: BlendDescriptor::REPLACE,
+            alpha_blend: BlendDescriptor::REPLACE,
+            write_mask: ColorWrite::ALL,
+        }
+    }
+
+    /// Standard "over" alpha blending: `src * src_alpha + dst * (1 - src_alpha)`.
+    pub fn alpha_blend(format: TextureFormat) -> Self {
+        ColorStateDescriptor {
+            format,
+            color_blend: BlendDescriptor {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha_blend: BlendDescriptor {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            write_mask: ColorWrite::ALL,
+        }
+    }
+
+    /// Returns true if any blend factor is [BlendFactor::BlendColor] or [BlendFactor::OneMinusBlendColor]. The blend
+    /// constant is not part of the pipeline, so it must be set in the render pass that uses this color state.
+    pub fn uses_blend_constant(&self) -> bool {
+        let is_constant = |factor: BlendFactor| {
+            matches!(
+                factor,
+                BlendFactor::BlendColor | BlendFactor::OneMinusBlendColor
+            )
+        };
+        is_constant(self.color_blend.src_factor)
+            || is_constant(self.color_blend.dst_factor)
+            || is_constant(self.alpha_blend.src_factor)
+            || is_constant(self.alpha_blend.dst_factor)
+    }
+
+    /// Premultiplied alpha blending: `src + dst * (1 - src_alpha)`, for sources whose color was already multiplied by
+    /// their alpha.
+    pub fn premultiplied_alpha(format: TextureFormat) -> Self {
+        let blend = BlendDescriptor {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            operation: BlendOperation::Add,
+        };
+        ColorStateDescriptor {
+            format,
+            color_blend: blend.clone(),
+            alpha_blend: blend,
+            write_mask: ColorWrite::ALL,
+        }
+    }
+
+    /// Additive blending: `src * src_alpha + dst`.
+    pub fn additive(format: TextureFormat) -> Self {
+        ColorStateDescriptor {
+            format,
+            color_blend: BlendDescriptor {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha_blend: BlendDescriptor {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            write_mask: ColorWrite::ALL,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 pub struct BlendDescriptor {
     pub src_factor: BlendFactor,
     pub dst_factor: BlendFactor,
@@ -134,11 +356,13 @@ impl BlendDescriptor {
 
 bitflags::bitflags! {
     #[repr(transparent)]
+    #[derive(Serialize, Deserialize)]
     pub struct ColorWrite: u32 {
         const RED = 1;
         const GREEN = 2;
         const BLUE = 4;
         const ALPHA = 8;
+        /// The red, green and blue channels, without alpha.
         const COLOR = 7;
         const ALL = 15;
     }
@@ -150,7 +374,7 @@ impl Default for ColorWrite {
     }
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum BlendFactor {
     Zero = 0,
     One = 1,
@@ -167,7 +391,7 @@ pub enum BlendFactor {
     OneMinusBlendColor = 12,
 }
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum BlendOperation {
     Add = 0,
     Subtract = 1,
@@ -193,3 +417,266 @@ impl Default for IndexFormat {
         IndexFormat::Uint32
     }
 }
+
+impl IndexFormat {
+    /// The size of a single index in bytes
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            IndexFormat::Uint16 => 2,
+            IndexFormat::Uint32 => 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_function_inverse() {
+        assert_eq!(CompareFunction::Never.inverse(), CompareFunction::Never);
+        assert_eq!(CompareFunction::Less.inverse(), CompareFunction::Greater);
+        assert_eq!(CompareFunction::Equal.inverse(), CompareFunction::Equal);
+        assert_eq!(
+            CompareFunction::LessEqual.inverse(),
+            CompareFunction::GreaterEqual
+        );
+        assert_eq!(CompareFunction::Greater.inverse(), CompareFunction::Less);
+        assert_eq!(
+            CompareFunction::NotEqual.inverse(),
+            CompareFunction::NotEqual
+        );
+        assert_eq!(
+            CompareFunction::GreaterEqual.inverse(),
+            CompareFunction::LessEqual
+        );
+        assert_eq!(CompareFunction::Always.inverse(), CompareFunction::Always);
+    }
+
+    #[test]
+    fn test_primitive_topology_from_str() {
+        assert_eq!("point-list".parse(), Ok(PrimitiveTopology::PointList));
+        assert_eq!("line-list".parse(), Ok(PrimitiveTopology::LineList));
+        assert_eq!("line-strip".parse(), Ok(PrimitiveTopology::LineStrip));
+        assert_eq!("triangle-list".parse(), Ok(PrimitiveTopology::TriangleList));
+        assert_eq!(
+            "triangle-strip".parse(),
+            Ok(PrimitiveTopology::TriangleStrip)
+        );
+        assert_eq!(
+            "TriangleList".parse::<PrimitiveTopology>(),
+            Err(UnknownPrimitiveTopology("TriangleList".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_primitive_topology_is_strip() {
+        assert!(PrimitiveTopology::LineStrip.is_strip());
+        assert!(PrimitiveTopology::TriangleStrip.is_strip());
+        assert!(!PrimitiveTopology::PointList.is_strip());
+        assert!(!PrimitiveTopology::LineList.is_strip());
+        assert!(!PrimitiveTopology::TriangleList.is_strip());
+    }
+
+    #[test]
+    fn test_index_format_size_in_bytes() {
+        assert_eq!(IndexFormat::Uint16.size_in_bytes(), 2);
+        assert_eq!(IndexFormat::Uint32.size_in_bytes(), 4);
+    }
+
+    #[test]
+    fn test_color_write_combinations() {
+        assert_eq!(
+            ColorWrite::RED | ColorWrite::GREEN | ColorWrite::BLUE,
+            ColorWrite::COLOR
+        );
+        assert_eq!(ColorWrite::COLOR | ColorWrite::ALPHA, ColorWrite::ALL);
+        assert_eq!(ColorWrite::ALL - ColorWrite::ALPHA, ColorWrite::COLOR);
+        assert!(!ColorWrite::COLOR.contains(ColorWrite::ALPHA));
+    }
+
+    #[test]
+    fn test_stencil_face_presets() {
+        let always_replace = StencilStateFaceDescriptor::ALWAYS_REPLACE;
+        assert_eq!(always_replace.compare, CompareFunction::Always);
+        assert_eq!(always_replace.fail_op, StencilOperation::Keep);
+        assert_eq!(always_replace.depth_fail_op, StencilOperation::Keep);
+        assert_eq!(always_replace.pass_op, StencilOperation::Replace);
+
+        let keep_if_equal = StencilStateFaceDescriptor::KEEP_IF_EQUAL;
+        assert_eq!(keep_if_equal.compare, CompareFunction::Equal);
+        assert_eq!(keep_if_equal.fail_op, StencilOperation::Keep);
+        assert_eq!(keep_if_equal.depth_fail_op, StencilOperation::Keep);
+        assert_eq!(keep_if_equal.pass_op, StencilOperation::Keep);
+    }
+
+    #[test]
+    fn test_depth_only() {
+        let state = DepthStencilStateDescriptor::depth_only(
+            TextureFormat::Depth24Plus,
+            CompareFunction::LessEqual,
+        );
+        assert_eq!(state.format, TextureFormat::Depth24Plus);
+        assert!(state.depth_write_enabled);
+        assert_eq!(state.depth_compare, CompareFunction::LessEqual);
+        assert_eq!(state.stencil.front, StencilStateFaceDescriptor::IGNORE);
+        assert_eq!(state.stencil.back, StencilStateFaceDescriptor::IGNORE);
+        assert_eq!(state.stencil.read_mask, 0);
+        assert_eq!(state.stencil.write_mask, 0);
+    }
+
+    #[test]
+    fn test_read_only() {
+        let state = DepthStencilStateDescriptor::read_only(
+            TextureFormat::Depth32Float,
+            CompareFunction::Less,
+        );
+        assert_eq!(state.format, TextureFormat::Depth32Float);
+        assert!(!state.depth_write_enabled);
+        assert_eq!(state.depth_compare, CompareFunction::Less);
+        assert_eq!(state.stencil.front, StencilStateFaceDescriptor::IGNORE);
+        assert_eq!(state.stencil.back, StencilStateFaceDescriptor::IGNORE);
+    }
+
+    #[test]
+    fn test_flip() {
+        assert_eq!(FrontFace::Ccw.flip(), FrontFace::Cw);
+        assert_eq!(FrontFace::Cw.flip(), FrontFace::Ccw);
+        assert_eq!(CullMode::None.flip(), CullMode::None);
+        assert_eq!(CullMode::Front.flip(), CullMode::Back);
+        assert_eq!(CullMode::Back.flip(), CullMode::Front);
+    }
+
+    #[test]
+    fn test_rasterization_state_presets() {
+        let no_cull = RasterizationStateDescriptor::default_no_cull();
+        assert_eq!(no_cull.cull_mode, CullMode::None);
+        assert_eq!(no_cull.front_face, FrontFace::Ccw);
+        assert_eq!(no_cull.depth_bias, 0);
+
+        let culled = RasterizationStateDescriptor::default_culled();
+        assert_eq!(culled.cull_mode, CullMode::Back);
+        assert_eq!(culled.front_face, FrontFace::Ccw);
+        assert_eq!(culled.depth_bias, 0);
+    }
+
+    #[test]
+    fn test_color_state_presets() {
+        let opaque = ColorStateDescriptor::opaque(TextureFormat::Rgba8Unorm);
+        assert_eq!(opaque.format, TextureFormat::Rgba8Unorm);
+        assert_eq!(opaque.color_blend, BlendDescriptor::REPLACE);
+        assert_eq!(opaque.alpha_blend, BlendDescriptor::REPLACE);
+        assert_eq!(opaque.write_mask, ColorWrite::ALL);
+
+        let alpha_blend = ColorStateDescriptor::alpha_blend(TextureFormat::Bgra8UnormSrgb);
+        assert_eq!(alpha_blend.format, TextureFormat::Bgra8UnormSrgb);
+        assert_eq!(
+            alpha_blend.color_blend,
+            BlendDescriptor {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            }
+        );
+        assert_eq!(
+            alpha_blend.alpha_blend,
+            BlendDescriptor {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            }
+        );
+
+        let additive = ColorStateDescriptor::additive(TextureFormat::Rgba16Float);
+        assert_eq!(additive.format, TextureFormat::Rgba16Float);
+        assert_eq!(additive.color_blend.src_factor, BlendFactor::SrcAlpha);
+        assert_eq!(additive.color_blend.dst_factor, BlendFactor::One);
+        assert_eq!(additive.alpha_blend.src_factor, BlendFactor::One);
+        assert_eq!(additive.alpha_blend.dst_factor, BlendFactor::One);
+        assert_eq!(additive.color_blend.operation, BlendOperation::Add);
+        assert_eq!(additive.alpha_blend.operation, BlendOperation::Add);
+    }
+
+    #[test]
+    fn test_uses_blend_constant() {
+        assert!(
+            !ColorStateDescriptor::alpha_blend(TextureFormat::Rgba8Unorm).uses_blend_constant()
+        );
+        assert!(!ColorStateDescriptor::opaque(TextureFormat::Rgba8Unorm).uses_blend_constant());
+
+        let mut color_state = ColorStateDescriptor::opaque(TextureFormat::Rgba8Unorm);
+        color_state.color_blend.src_factor = BlendFactor::BlendColor;
+        assert!(color_state.uses_blend_constant());
+
+        let mut color_state = ColorStateDescriptor::opaque(TextureFormat::Rgba8Unorm);
+        color_state.alpha_blend.dst_factor = BlendFactor::OneMinusBlendColor;
+        assert!(color_state.uses_blend_constant());
+    }
+
+    #[test]
+    fn test_premultiplied_alpha() {
+        let premultiplied = ColorStateDescriptor::premultiplied_alpha(TextureFormat::Rgba8Unorm);
+        assert_eq!(premultiplied.format, TextureFormat::Rgba8Unorm);
+        // out = src * 1 + dst * (1 - src_alpha), for both color and alpha
+        let expected = BlendDescriptor {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            operation: BlendOperation::Add,
+        };
+        assert_eq!(premultiplied.color_blend, expected);
+        assert_eq!(premultiplied.alpha_blend, expected);
+        assert_eq!(premultiplied.write_mask, ColorWrite::ALL);
+    }
+
+    fn ron_round_trip<T: Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+        let serialized = ron::ser::to_string(value).unwrap();
+        ron::de::from_str(&serialized).unwrap()
+    }
+
+    #[test]
+    fn test_ron_round_trip() {
+        let color_state = ColorStateDescriptor {
+            format: TextureFormat::Rgba16Float,
+            color_blend: BlendDescriptor {
+                src_factor: BlendFactor::BlendColor,
+                dst_factor: BlendFactor::OneMinusSrcColor,
+                operation: BlendOperation::ReverseSubtract,
+            },
+            alpha_blend: BlendDescriptor {
+                src_factor: BlendFactor::DstAlpha,
+                dst_factor: BlendFactor::Zero,
+                operation: BlendOperation::Max,
+            },
+            write_mask: ColorWrite::RED | ColorWrite::ALPHA,
+        };
+        assert_eq!(ron_round_trip(&color_state), color_state);
+
+        let depth_stencil_state = DepthStencilStateDescriptor {
+            format: TextureFormat::Depth24PlusStencil8,
+            depth_write_enabled: false,
+            depth_compare: CompareFunction::GreaterEqual,
+            stencil: StencilStateDescriptor {
+                front: StencilStateFaceDescriptor::ALWAYS_REPLACE,
+                back: StencilStateFaceDescriptor {
+                    compare: CompareFunction::NotEqual,
+                    fail_op: StencilOperation::Invert,
+                    depth_fail_op: StencilOperation::DecrementWrap,
+                    pass_op: StencilOperation::IncrementClamp,
+                },
+                read_mask: 0x0f,
+                write_mask: 0xf0,
+            },
+        };
+        assert_eq!(ron_round_trip(&depth_stencil_state), depth_stencil_state);
+
+        let rasterization_state = RasterizationStateDescriptor {
+            front_face: FrontFace::Cw,
+            cull_mode: CullMode::Front,
+            depth_bias: -2,
+            depth_bias_slope_scale: 1.5,
+            depth_bias_clamp: 0.25,
+            clamp_depth: true,
+        };
+        assert_eq!(ron_round_trip(&rasterization_state), rasterization_state);
+    }
+}
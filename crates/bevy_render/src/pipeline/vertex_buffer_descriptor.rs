@@ -1,10 +1,11 @@
 use super::VertexFormat;
 use bevy_utils::HashMap;
 use std::borrow::Cow;
+use thiserror::Error;
 
 pub use bevy_derive::AsVertexBufferDescriptor;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct VertexBufferDescriptor {
     pub name: Cow<'static, str>,
     pub stride: u64,
@@ -30,6 +31,46 @@ impl VertexBufferDescriptor {
 
         self.stride = descriptor.stride;
     }
+
+    /// Checks that every attribute fits within the stride and that no two attributes overlap.
+    pub fn validate(&self) -> Result<(), VertexBufferValidationError> {
+        let mut attributes = self.attributes.iter().collect::<Vec<_>>();
+        attributes.sort_by_key(|attribute| attribute.offset);
+
+        for (i, attribute) in attributes.iter().enumerate() {
+            let end = attribute.offset + attribute.format.get_size();
+            if end > self.stride {
+                return Err(VertexBufferValidationError::AttributeExceedsStride {
+                    attribute: attribute.name.to_string(),
+                    end,
+                    stride: self.stride,
+                });
+            }
+
+            if let Some(next) = attributes.get(i + 1) {
+                if end > next.offset {
+                    return Err(VertexBufferValidationError::OverlappingAttributes {
+                        first: attribute.name.to_string(),
+                        second: next.name.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VertexBufferValidationError {
+    #[error("Vertex attribute {attribute} ends at byte {end}, past the vertex buffer stride of {stride}.")]
+    AttributeExceedsStride {
+        attribute: String,
+        end: u64,
+        stride: u64,
+    },
+    #[error("Vertex attributes {first} and {second} overlap.")]
+    OverlappingAttributes { first: String, second: String },
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -67,3 +108,66 @@ impl VertexBufferDescriptors {
 pub trait AsVertexBufferDescriptor {
     fn as_vertex_buffer_descriptor() -> &'static VertexBufferDescriptor;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(
+        name: &'static str,
+        offset: u64,
+        format: VertexFormat,
+    ) -> VertexAttributeDescriptor {
+        VertexAttributeDescriptor {
+            name: name.into(),
+            offset,
+            format,
+            shader_location: 0,
+        }
+    }
+
+    fn descriptor(
+        stride: u64,
+        attributes: Vec<VertexAttributeDescriptor>,
+    ) -> VertexBufferDescriptor {
+        VertexBufferDescriptor {
+            name: "Vertex".into(),
+            stride,
+            step_mode: InputStepMode::Vertex,
+            attributes,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let position_normal = vec![
+            attribute("Vertex_Normal", 12, VertexFormat::Float3),
+            attribute("Vertex_Position", 0, VertexFormat::Float3),
+        ];
+        assert_eq!(descriptor(24, position_normal.clone()).validate(), Ok(()));
+
+        assert_eq!(
+            descriptor(20, position_normal).validate(),
+            Err(VertexBufferValidationError::AttributeExceedsStride {
+                attribute: "Vertex_Normal".to_string(),
+                end: 24,
+                stride: 20,
+            })
+        );
+
+        assert_eq!(
+            descriptor(
+                24,
+                vec![
+                    attribute("Vertex_Position", 0, VertexFormat::Float3),
+                    attribute("Vertex_Uv", 8, VertexFormat::Float2),
+                ]
+            )
+            .validate(),
+            Err(VertexBufferValidationError::OverlappingAttributes {
+                first: "Vertex_Position".to_string(),
+                second: "Vertex_Uv".to_string(),
+            })
+        );
+    }
+}
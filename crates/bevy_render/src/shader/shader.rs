@@ -160,12 +160,15 @@ pub struct ShaderStagesIterator<'a> {
 }
 
 impl<'a> Iterator for ShaderStagesIterator<'a> {
-    type Item = Handle<Shader>;
+    type Item = (ShaderStage, Handle<Shader>);
 
     fn next(&mut self) -> Option<Self::Item> {
         let ret = match self.state {
-            0 => Some(self.shader_stages.vertex),
-            1 => self.shader_stages.fragment,
+            0 => Some((ShaderStage::Vertex, self.shader_stages.vertex)),
+            1 => self
+                .shader_stages
+                .fragment
+                .map(|fragment| (ShaderStage::Fragment, fragment)),
             _ => None,
         };
         self.state += 1;
@@ -181,6 +184,7 @@ impl ShaderStages {
         }
     }
 
+    /// Iterates over the stages that have a shader set, along with their shader handles
     pub fn iter(&self) -> ShaderStagesIterator {
         ShaderStagesIterator {
             shader_stages: &self,
@@ -188,3 +192,28 @@ impl ShaderStages {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shader_stages_iter() {
+        let vertex = Handle::<Shader>::new();
+        let mut shader_stages = ShaderStages::new(vertex);
+        assert_eq!(
+            shader_stages.iter().collect::<Vec<_>>(),
+            vec![(ShaderStage::Vertex, vertex)]
+        );
+
+        let fragment = Handle::<Shader>::new();
+        shader_stages.fragment = Some(fragment);
+        assert_eq!(
+            shader_stages.iter().collect::<Vec<_>>(),
+            vec![
+                (ShaderStage::Vertex, vertex),
+                (ShaderStage::Fragment, fragment)
+            ]
+        );
+    }
+}
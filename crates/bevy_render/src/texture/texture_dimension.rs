@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 // NOTE: These are currently just copies of the wgpu types, but they might change in the future
 
 /// Dimensions of a particular texture view.
@@ -44,7 +46,7 @@ pub struct PixelInfo {
 ///
 /// If there is a conversion in the format (such as srgb -> linear), The conversion listed is for
 /// loading from texture in a shader. When writing to the texture, the opposite conversion takes place.
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum TextureFormat {
     // Normal 8 bit formats
     R8Unorm = 0,
@@ -204,6 +206,16 @@ impl TextureFormat {
         let info = self.pixel_info();
         info.type_size * info.num_components
     }
+
+    /// Returns true if this format can only be used for depth (and stencil) attachments.
+    pub fn is_depth_format(&self) -> bool {
+        matches!(
+            self,
+            TextureFormat::Depth32Float
+                | TextureFormat::Depth24Plus
+                | TextureFormat::Depth24PlusStencil8
+        )
+    }
 }
 
 bitflags::bitflags! {
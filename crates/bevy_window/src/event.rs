@@ -35,8 +35,25 @@ pub struct WindowCloseRequested {
     pub id: WindowId,
 }
 
+/// An event that is sent whenever a window gains or loses focus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFocused {
+    pub id: WindowId,
+    pub focused: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct CursorMoved {
     pub id: WindowId,
     pub position: Vec2,
 }
+
+/// An event that is sent after a window tried to lock or unlock the cursor, as requested by
+/// [Window::set_cursor_lock_mode](crate::Window::set_cursor_lock_mode). `succeeded` is false if the platform
+/// rejected the request, for example because the window is not focused.
+#[derive(Debug, Clone)]
+pub struct CursorLockStateChanged {
+    pub id: WindowId,
+    pub locked: bool,
+    pub succeeded: bool,
+}
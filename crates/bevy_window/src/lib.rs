@@ -1,8 +1,10 @@
+mod cursor;
 mod event;
 mod system;
 mod window;
 mod windows;
 
+pub use cursor::*;
 pub use event::*;
 pub use system::*;
 pub use window::*;
@@ -37,6 +39,8 @@ impl Plugin for WindowPlugin {
             .add_event::<WindowCloseRequested>()
             .add_event::<CloseWindow>()
             .add_event::<CursorMoved>()
+            .add_event::<CursorLockStateChanged>()
+            .add_event::<WindowFocused>()
             .init_resource::<Windows>();
 
         if self.add_primary_window {
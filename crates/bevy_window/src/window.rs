@@ -1,3 +1,5 @@
+use super::CursorIcon;
+use bevy_math::Vec2;
 use uuid::Uuid;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -42,6 +44,7 @@ pub struct Window {
     decorations: bool,
     cursor_visible: bool,
     cursor_locked: bool,
+    cursor_icon: CursorIcon,
     mode: WindowMode,
     #[cfg(target_arch = "wasm32")]
     pub canvas: Option<String>,
@@ -76,6 +79,12 @@ pub enum WindowCommand {
     SetCursorVisibility {
         visible: bool,
     },
+    SetCursorPosition {
+        position: Vec2,
+    },
+    SetCursorIcon {
+        icon: CursorIcon,
+    },
 }
 
 /// Defines the way a window is displayed
@@ -102,6 +111,7 @@ impl Window {
             decorations: window_descriptor.decorations,
             cursor_visible: window_descriptor.cursor_visible,
             cursor_locked: window_descriptor.cursor_locked,
+            cursor_icon: CursorIcon::default(),
             mode: window_descriptor.mode,
             #[cfg(target_arch = "wasm32")]
             canvas: window_descriptor.canvas.clone(),
@@ -175,16 +185,38 @@ impl Window {
             .push(WindowCommand::SetDecorations { decorations });
     }
 
+    /// Returns true if the cursor is locked to the window. After [Window::set_cursor_lock_mode] this is the requested
+    /// lock mode until the backend applies it. If the backend fails to apply it, this is reset to the lock mode that
+    /// the backend last applied. The same is done when a window that starts with a locked cursor can't lock it.
     pub fn cursor_locked(&self) -> bool {
         self.cursor_locked
     }
 
+    #[doc(hidden)]
+    pub fn update_cursor_lock_mode_from_backend(&mut self, locked: bool) {
+        self.cursor_locked = locked;
+    }
+
+    /// Locks the cursor to the window. The cursor is not hidden: use [Window::set_cursor_visibility] for that.
+    /// A [CursorLockStateChanged](crate::CursorLockStateChanged) event is sent once the backend has applied the request.
+    ///
+    /// Platform-specific behavior:
+    /// * Windows, Linux (X11 and Wayland): the cursor is confined to the window, but can still move
+    /// * macOS: the cursor is locked in place
+    /// * Web, iOS, Android: not supported. Requests are logged and otherwise ignored
     pub fn set_cursor_lock_mode(&mut self, lock_mode: bool) {
         self.cursor_locked = lock_mode;
         self.command_queue
             .push(WindowCommand::SetCursorLockMode { locked: lock_mode });
     }
 
+    /// Unlocks the cursor if it is locked and locks it if it is unlocked. The current state is read back from
+    /// [Window::cursor_locked], so callers don't need to track it themselves. If the backend rejected the last request,
+    /// [Window::cursor_locked] has been restored to the lock mode the backend last applied, and the toggle flips that.
+    pub fn toggle_cursor_lock_mode(&mut self) {
+        self.set_cursor_lock_mode(!self.cursor_locked);
+    }
+
     pub fn cursor_visible(&self) -> bool {
         self.cursor_visible
     }
@@ -196,6 +228,32 @@ impl Window {
         });
     }
 
+    /// Shows the cursor if it is hidden and hides it if it is visible. The current state is read back from
+    /// [Window::cursor_visible], so callers don't need to track it themselves.
+    pub fn toggle_cursor_visibility(&mut self) {
+        self.set_cursor_visibility(!self.cursor_visible);
+    }
+
+    /// Moves the cursor to `position`, in window coordinates with the origin at the bottom left
+    /// (the same coordinates used by [CursorMoved](crate::CursorMoved) events). The request is ignored if the
+    /// backend has no window for this window's id, for example because it was closed.
+    pub fn set_cursor_position(&mut self, position: Vec2) {
+        self.command_queue
+            .push(WindowCommand::SetCursorPosition { position });
+    }
+
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icon
+    }
+
+    /// Sets the cursor icon shown while the cursor is over this window. The request is ignored if the backend has no
+    /// window for this window's id.
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.cursor_icon = icon;
+        self.command_queue
+            .push(WindowCommand::SetCursorIcon { icon });
+    }
+
     pub fn mode(&self) -> WindowMode {
         self.mode
     }
@@ -252,3 +310,67 @@ impl Default for WindowDescriptor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_utils::HashMap;
+
+    #[test]
+    fn test_toggle_cursor_lock_mode() {
+        let mut window = Window::new(WindowId::primary(), &WindowDescriptor::default());
+        assert!(!window.cursor_locked());
+        window.toggle_cursor_lock_mode();
+        assert!(window.cursor_locked());
+        window.toggle_cursor_lock_mode();
+        assert!(!window.cursor_locked());
+
+        // the backend failed to lock the cursor
+        window.toggle_cursor_lock_mode();
+        window.update_cursor_lock_mode_from_backend(false);
+        window.toggle_cursor_lock_mode();
+        assert!(
+            window.cursor_locked(),
+            "toggling after a failed lock requests a lock again"
+        );
+
+        // the backend failed to unlock the cursor, which was never locked
+        window.update_cursor_lock_mode_from_backend(false);
+        window.set_cursor_lock_mode(false);
+        window.update_cursor_lock_mode_from_backend(false);
+        assert!(!window.cursor_locked());
+        window.toggle_cursor_lock_mode();
+        assert!(
+            window.cursor_locked(),
+            "toggling after a failed unlock of an unlocked cursor requests a lock"
+        );
+
+        // the backend failed to unlock a locked cursor
+        window.toggle_cursor_lock_mode();
+        window.update_cursor_lock_mode_from_backend(true);
+        assert!(window.cursor_locked());
+        window.toggle_cursor_lock_mode();
+        assert!(
+            !window.cursor_locked(),
+            "toggling after a failed unlock requests an unlock again"
+        );
+    }
+
+    #[test]
+    fn test_window_id_as_key() {
+        let primary = WindowId::primary();
+        let other = WindowId::new();
+        assert_ne!(primary, other);
+
+        let mut titles = HashMap::default();
+        titles.insert(primary, "primary");
+        titles.insert(other, "other");
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles[&primary], "primary");
+        assert_eq!(titles[&other], "other");
+
+        assert_eq!(primary.to_string(), "0".repeat(32));
+        assert_eq!(other.to_string().len(), 32);
+        assert_ne!(primary.to_string(), other.to_string());
+    }
+}
@@ -34,4 +34,42 @@ impl Windows {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Window> {
         self.windows.values_mut()
     }
+
+    /// Calls [Window::set_cursor_lock_mode] on every window.
+    pub fn set_cursor_lock_mode_all(&mut self, lock_mode: bool) {
+        for window in self.iter_mut() {
+            window.set_cursor_lock_mode(lock_mode);
+        }
+    }
+
+    /// Calls [Window::set_cursor_visibility] on every window.
+    pub fn set_cursor_visibility_all(&mut self, visible: bool) {
+        for window in self.iter_mut() {
+            window.set_cursor_visibility(visible);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WindowDescriptor;
+
+    #[test]
+    fn test_set_cursor_all() {
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+        ));
+        windows.add(Window::new(WindowId::new(), &WindowDescriptor::default()));
+
+        windows.set_cursor_lock_mode_all(true);
+        windows.set_cursor_visibility_all(false);
+        assert_eq!(windows.iter().count(), 2);
+        for window in windows.iter() {
+            assert!(window.cursor_locked());
+            assert!(!window.cursor_visible());
+        }
+    }
 }
@@ -12,7 +12,8 @@ use bevy_app::{prelude::*, AppExit};
 use bevy_ecs::{IntoThreadLocalSystem, Resources, World};
 use bevy_math::Vec2;
 use bevy_window::{
-    CreateWindow, CursorMoved, Window, WindowCloseRequested, WindowCreated, WindowResized, Windows,
+    CreateWindow, CursorLockStateChanged, CursorMoved, Window, WindowCloseRequested, WindowCreated,
+    WindowFocused, WindowResized, Windows,
 };
 use winit::{
     event::{self, DeviceEvent, Event, WindowEvent},
@@ -38,11 +39,15 @@ impl Plugin for WinitPlugin {
 }
 
 fn change_window(_: &mut World, resources: &mut Resources) {
-    let winit_windows = resources.get::<WinitWindows>().unwrap();
+    let mut winit_windows = resources.get_mut::<WinitWindows>().unwrap();
     let mut windows = resources.get_mut::<Windows>().unwrap();
+    let mut cursor_lock_state_changed_events = resources
+        .get_mut::<Events<CursorLockStateChanged>>()
+        .unwrap();
 
     for bevy_window in windows.iter_mut() {
         let id = bevy_window.id();
+        let mut cursor_lock_failed = false;
         for command in bevy_window.drain_commands() {
             match command {
                 bevy_window::WindowCommand::SetWindowMode {
@@ -86,14 +91,54 @@ fn change_window(_: &mut World, resources: &mut Resources) {
                 }
                 bevy_window::WindowCommand::SetCursorLockMode { locked } => {
                     let window = winit_windows.get_window(id).unwrap();
-                    window.set_cursor_grab(locked).unwrap();
+                    let succeeded = match window.set_cursor_grab(locked) {
+                        Ok(()) => {
+                            winit_windows.cursor_lock_modes.insert(id, locked);
+                            true
+                        }
+                        Err(err) => {
+                            log::error!("Unable to set cursor lock mode: {}", err);
+                            cursor_lock_failed = true;
+                            false
+                        }
+                    };
+                    cursor_lock_state_changed_events.send(CursorLockStateChanged {
+                        id,
+                        locked,
+                        succeeded,
+                    });
                 }
                 bevy_window::WindowCommand::SetCursorVisibility { visible } => {
                     let window = winit_windows.get_window(id).unwrap();
                     window.set_cursor_visible(visible);
                 }
+                bevy_window::WindowCommand::SetCursorIcon { icon } => {
+                    if let Some(window) = winit_windows.get_window(id) {
+                        window.set_cursor_icon(converters::convert_cursor_icon(icon));
+                    }
+                }
+                bevy_window::WindowCommand::SetCursorPosition { position } => {
+                    // the winit window may not exist (yet or anymore) for this id. the command is
+                    // ignored in that case instead of panicking
+                    if let Some(window) = winit_windows.get_window(id) {
+                        let inner_size = window.inner_size();
+                        // move origin to top left
+                        let y_position = inner_size.height as f32 - position.y();
+                        if let Err(err) = window.set_cursor_position(
+                            winit::dpi::PhysicalPosition::new(position.x(), y_position),
+                        ) {
+                            log::error!("Unable to set cursor position: {}", err);
+                        }
+                    }
+                }
             }
         }
+
+        // a failed request leaves the cursor in the last applied lock mode. this is not necessarily the opposite of
+        // the request: some platforms reject both locking and unlocking
+        if cursor_lock_failed {
+            bevy_window.update_cursor_lock_mode_from_backend(winit_windows.cursor_lock_mode(id));
+        }
     }
 }
 
@@ -191,6 +236,9 @@ pub fn winit_runner(mut app: App) {
                 window.update_resolution_from_backend(size.width, size.height);
 
                 let mut resize_events = app.resources.get_mut::<Events<WindowResized>>().unwrap();
+                // winit can report many resizes per frame (ex: while dragging a window border). only the latest
+                // size matters, so older resize events for this window are replaced
+                resize_events.retain(|event| event.id != window_id);
                 resize_events.send(WindowResized {
                     id: window_id,
                     height: window.height() as usize,
@@ -211,6 +259,17 @@ pub fn winit_runner(mut app: App) {
                     let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
                     window_close_requested_events.send(WindowCloseRequested { id: window_id });
                 }
+                WindowEvent::Focused(focused) => {
+                    let mut window_focused_events =
+                        app.resources.get_mut::<Events<WindowFocused>>().unwrap();
+                    let winit_windows = app.resources.get_mut::<WinitWindows>().unwrap();
+                    let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
+                    // some platforms report the same focus change more than once
+                    window_focused_events.send_unique(WindowFocused {
+                        id: window_id,
+                        focused,
+                    });
+                }
                 WindowEvent::KeyboardInput { ref input, .. } => {
                     let mut keyboard_input_events =
                         app.resources.get_mut::<Events<KeyboardInput>>().unwrap();
@@ -296,10 +355,27 @@ fn handle_create_window_events(
     let mut windows = resources.get_mut::<Windows>().unwrap();
     let create_window_events = resources.get::<Events<CreateWindow>>().unwrap();
     let mut window_created_events = resources.get_mut::<Events<WindowCreated>>().unwrap();
+    let mut cursor_lock_state_changed_events = resources
+        .get_mut::<Events<CursorLockStateChanged>>()
+        .unwrap();
     for create_window_event in create_window_event_reader.iter(&create_window_events) {
-        let window = Window::new(create_window_event.id, &create_window_event.descriptor);
-        winit_windows.create_window(event_loop, &window);
+        let mut window = Window::new(create_window_event.id, &create_window_event.descriptor);
         let window_id = window.id();
+        if let Some(result) = winit_windows.create_window(event_loop, &window) {
+            let succeeded = match result {
+                Ok(()) => true,
+                Err(err) => {
+                    log::error!("Unable to lock cursor: {}", err);
+                    window.update_cursor_lock_mode_from_backend(false);
+                    false
+                }
+            };
+            cursor_lock_state_changed_events.send(CursorLockStateChanged {
+                id: window_id,
+                locked: true,
+                succeeded,
+            });
+        }
         windows.add(window);
         window_created_events.send(WindowCreated { id: window_id });
     }
@@ -6,14 +6,18 @@ pub struct WinitWindows {
     pub windows: HashMap<winit::window::WindowId, winit::window::Window>,
     pub window_id_to_winit: HashMap<WindowId, winit::window::WindowId>,
     pub winit_to_window_id: HashMap<winit::window::WindowId, WindowId>,
+    /// The cursor lock mode that the backend last applied to each window.
+    pub cursor_lock_modes: HashMap<WindowId, bool>,
 }
 
 impl WinitWindows {
+    /// Creates the winit window for `window`. If the window should start with a locked cursor, the result of locking
+    /// it is returned.
     pub fn create_window(
         &mut self,
         event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
         window: &Window,
-    ) {
+    ) -> Option<Result<(), winit::error::ExternalError>> {
         #[cfg(target_os = "windows")]
         let mut winit_window_builder = {
             use winit::platform::windows::WindowBuilderExtWindows;
@@ -71,9 +75,13 @@ impl WinitWindows {
 
         let winit_window = winit_window_builder.build(&event_loop).unwrap();
 
-        winit_window
-            .set_cursor_grab(window.cursor_locked())
-            .unwrap();
+        let cursor_lock_result = if window.cursor_locked() {
+            Some(winit_window.set_cursor_grab(true))
+        } else {
+            None
+        };
+        self.cursor_lock_modes
+            .insert(window.id(), matches!(cursor_lock_result, Some(Ok(()))));
         winit_window.set_cursor_visible(window.cursor_visible());
 
         self.window_id_to_winit
@@ -98,6 +106,7 @@ impl WinitWindows {
         }
 
         self.windows.insert(winit_window.id(), winit_window);
+        cursor_lock_result
     }
 
     pub fn get_window(&self, id: WindowId) -> Option<&winit::window::Window> {
@@ -106,9 +115,29 @@ impl WinitWindows {
             .and_then(|id| self.windows.get(id))
     }
 
+    pub fn get_primary_window(&self) -> Option<&winit::window::Window> {
+        self.get_window(WindowId::primary())
+    }
+
     pub fn get_window_id(&self, id: winit::window::WindowId) -> Option<WindowId> {
         self.winit_to_window_id.get(&id).cloned()
     }
+
+    /// Returns the scale factor of the window, which maps logical pixels to physical pixels. This is usually greater
+    /// than 1.0 on HiDPI displays.
+    pub fn get_scale_factor(&self, id: WindowId) -> Option<f64> {
+        self.get_window(id).map(|window| window.scale_factor())
+    }
+
+    /// Returns the cursor lock mode that the backend last applied to the window. This is false for unknown windows.
+    pub fn cursor_lock_mode(&self, id: WindowId) -> bool {
+        self.cursor_lock_modes.get(&id).cloned().unwrap_or(false)
+    }
+
+    /// Returns the ids of all windows that have been created by the backend, including the primary window.
+    pub fn iter_window_ids(&self) -> impl Iterator<Item = WindowId> + '_ {
+        self.window_id_to_winit.keys().cloned()
+    }
 }
 pub fn get_fitting_videomode(
     monitor: &winit::monitor::MonitorHandle,
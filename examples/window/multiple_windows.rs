@@ -10,6 +10,7 @@ use bevy::{
         texture::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsage},
     },
     window::{CreateWindow, WindowDescriptor, WindowId},
+    winit::WinitWindows,
 };
 
 /// This example creates a second window and draws a mesh from two different cameras.
@@ -17,9 +18,25 @@ fn main() {
     App::build()
         .add_default_plugins()
         .add_startup_system(setup.system())
+        .add_system(hide_cursor.system())
         .run();
 }
 
+/// This system hides the cursor in every open window when the space bar is pressed
+fn hide_cursor(
+    input: Res<Input<KeyCode>>,
+    winit_windows: Res<WinitWindows>,
+    mut windows: ResMut<Windows>,
+) {
+    if input.just_pressed(KeyCode::Space) {
+        for id in winit_windows.iter_window_ids() {
+            if let Some(window) = windows.get_mut(id) {
+                window.set_cursor_visibility(false);
+            }
+        }
+    }
+}
+
 fn setup(
     mut commands: Commands,
     mut create_window_events: ResMut<Events<CreateWindow>>,
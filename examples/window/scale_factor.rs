@@ -0,0 +1,37 @@
+use bevy::{prelude::*, window::WindowCreated, winit::WinitWindows};
+
+/// This example prints the scale factor of each window when it is created, and the size of the primary window
+fn main() {
+    App::build()
+        .add_default_plugins()
+        .add_system(print_scale_factor_system.system())
+        .run();
+}
+
+#[derive(Default)]
+struct State {
+    window_created_event_reader: EventReader<WindowCreated>,
+}
+
+fn print_scale_factor_system(
+    mut state: Local<State>,
+    winit_windows: Res<WinitWindows>,
+    window_created_events: Res<Events<WindowCreated>>,
+) {
+    for event in state
+        .window_created_event_reader
+        .iter(&window_created_events)
+    {
+        if let Some(scale_factor) = winit_windows.get_scale_factor(event.id) {
+            println!("Window {} has a scale factor of {}", event.id, scale_factor);
+        }
+
+        if event.id.is_primary() {
+            let size = winit_windows.get_primary_window().unwrap().inner_size();
+            println!(
+                "The primary window has an inner size of {}x{} physical pixels",
+                size.width, size.height
+            );
+        }
+    }
+}
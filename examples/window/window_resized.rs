@@ -0,0 +1,29 @@
+use bevy::{prelude::*, window::WindowResized};
+
+/// This example prints the new window size whenever a window is resized
+fn main() {
+    App::build()
+        .add_default_plugins()
+        .add_system(print_window_size_system.system())
+        .run();
+}
+
+#[derive(Default)]
+struct State {
+    window_resized_event_reader: EventReader<WindowResized>,
+}
+
+fn print_window_size_system(
+    mut state: Local<State>,
+    window_resized_events: Res<Events<WindowResized>>,
+) {
+    for event in state
+        .window_resized_event_reader
+        .iter(&window_resized_events)
+    {
+        println!(
+            "Window {} resized to {}x{}",
+            event.id, event.width, event.height
+        );
+    }
+}
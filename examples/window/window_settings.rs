@@ -1,4 +1,7 @@
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    window::{CursorIcon, WindowFocused, WindowMode},
+};
 
 /// This example illustrates how to customize the default window settings
 fn main() {
@@ -9,11 +12,18 @@ fn main() {
             height: 300,
             vsync: true,
             resizable: false,
+            // the cursor state can also be set when the window is created
+            cursor_visible: true,
+            cursor_locked: false,
             ..Default::default()
         })
         .add_default_plugins()
         .add_system(change_title.system())
         .add_system(toggle_cursor.system())
+        .add_system(center_cursor.system())
+        .add_system(toggle_fullscreen.system())
+        .add_system(release_cursor_on_focus_loss.system())
+        .add_system(change_cursor_icon.system())
         .run();
 }
 
@@ -26,11 +36,68 @@ fn change_title(time: Res<Time>, mut windows: ResMut<Windows>) {
     ));
 }
 
-/// This system toggles the cursor's visibility when the space bar is pressed
+/// This system toggles the cursor's visibility and lock mode when the space bar is pressed
 fn toggle_cursor(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
     let window = windows.get_primary_mut().unwrap();
     if input.just_pressed(KeyCode::Space) {
-        window.set_cursor_lock_mode(!window.cursor_locked());
-        window.set_cursor_visibility(!window.cursor_visible());
+        window.toggle_cursor_lock_mode();
+        window.toggle_cursor_visibility();
+    }
+}
+
+/// This system moves the cursor to the center of the window when "C" is pressed
+fn center_cursor(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
+    let window = windows.get_primary_mut().unwrap();
+    if input.just_pressed(KeyCode::C) {
+        let center = Vec2::new(window.width() as f32 / 2.0, window.height() as f32 / 2.0);
+        window.set_cursor_position(center);
+    }
+}
+
+/// This system switches between windowed and borderless fullscreen mode when F11 is pressed
+fn toggle_fullscreen(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
+    let window = windows.get_primary_mut().unwrap();
+    if input.just_pressed(KeyCode::F11) {
+        let mode = match window.mode() {
+            WindowMode::Windowed => WindowMode::BorderlessFullscreen,
+            _ => WindowMode::Windowed,
+        };
+        window.set_mode(mode);
+    }
+}
+
+#[derive(Default)]
+struct FocusState {
+    window_focused_event_reader: EventReader<WindowFocused>,
+}
+
+/// This system unlocks and shows the cursor when the window loses focus
+fn release_cursor_on_focus_loss(
+    mut state: Local<FocusState>,
+    window_focused_events: Res<Events<WindowFocused>>,
+    mut windows: ResMut<Windows>,
+) {
+    for event in state
+        .window_focused_event_reader
+        .iter(&window_focused_events)
+    {
+        if event.focused {
+            continue;
+        }
+
+        if let Some(window) = windows.get_mut(event.id) {
+            window.set_cursor_lock_mode(false);
+            window.set_cursor_visibility(true);
+        }
+    }
+}
+
+/// This system shows a hand cursor while "H" is held down
+fn change_cursor_icon(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
+    let window = windows.get_primary_mut().unwrap();
+    if input.just_pressed(KeyCode::H) {
+        window.set_cursor_icon(CursorIcon::Hand);
+    } else if input.just_released(KeyCode::H) {
+        window.set_cursor_icon(CursorIcon::Default);
     }
 }